@@ -0,0 +1,183 @@
+use quote::ToTokens;
+use syn::{
+    BinOp, Expr, GenericArgument, LitFloat, PathArguments, Token, Type, UnOp,
+    parse::{Parse, ParseStream},
+    parse_quote,
+};
+
+pub(crate) struct TypeAndPath {
+    pub(crate) ty:   Type,
+    pub(crate) path: Expr,
+}
+
+impl Parse for TypeAndPath {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ty = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let path = input.parse()?;
+
+        Ok(TypeAndPath {
+            ty,
+            path,
+        })
+    }
+}
+
+/// Element types whose values need to be rewritten.
+enum Leaf {
+    /// `f32` or `f64`
+    Float(&'static str),
+    /// `NonZero<T>` or its aliases like `NonZeroU16`, with the name of `T`
+    NonZero(String),
+}
+
+const NON_ZERO_TYPES: [&str; 12] =
+    ["i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize"];
+
+/// Gets the single generic type argument of the last segment of a type path, like `T` of `Option<T>`.
+fn generic_argument<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let Type::Path(ty) = ty else {
+        return None;
+    };
+
+    let segment = ty.path.segments.last()?;
+
+    if ty.qself.is_some() || segment.ident != name {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+            match &arguments.args[0] {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+fn leaf(ty: &Type) -> Option<Leaf> {
+    match ty {
+        Type::Paren(ty) => return leaf(&ty.elem),
+        Type::Group(ty) => return leaf(&ty.elem),
+        _ => (),
+    }
+
+    if let Some(ty) = generic_argument(ty, "NonZero") {
+        let name = ty.to_token_stream().to_string();
+
+        return NON_ZERO_TYPES.contains(&name.as_str()).then_some(Leaf::NonZero(name));
+    }
+
+    let Type::Path(ty) = ty else {
+        return None;
+    };
+
+    let segment = ty.path.segments.last()?;
+
+    if ty.qself.is_some() || !segment.arguments.is_none() {
+        return None;
+    }
+
+    match segment.ident.to_string().as_str() {
+        "f32" if ty.path.segments.len() == 1 => Some(Leaf::Float("f32")),
+        "f64" if ty.path.segments.len() == 1 => Some(Leaf::Float("f64")),
+        ident => ident
+            .strip_prefix("NonZero")
+            .map(str::to_lowercase)
+            .filter(|name| NON_ZERO_TYPES.contains(&name.as_str()))
+            .map(Leaf::NonZero),
+    }
+}
+
+/// Checks whether the values of a type need to be rewritten by `rewrite`.
+pub(crate) fn needs_rewrite(ty: &Type) -> bool {
+    match ty {
+        Type::Array(ty) => needs_rewrite(&ty.elem),
+        Type::Slice(ty) => needs_rewrite(&ty.elem),
+        Type::Tuple(ty) => ty.elems.iter().any(needs_rewrite),
+        Type::Paren(ty) => needs_rewrite(&ty.elem),
+        Type::Group(ty) => needs_rewrite(&ty.elem),
+        _ => generic_argument(ty, "Option").map_or_else(|| leaf(ty).is_some(), needs_rewrite),
+    }
+}
+
+/// Turns unsuffixed integer literals which are values of the float type `name` into float literals. Operands of casts keep their own types.
+fn floatify(expr: &mut Expr, name: &str) {
+    match expr {
+        Expr::Lit(exp) => {
+            if let syn::Lit::Int(lit) = &exp.lit {
+                if lit.suffix().is_empty() {
+                    // The literal can be hexadecimal, so use its decimal digits.
+                    let float =
+                        LitFloat::new(&format!("{}{name}", lit.base10_digits()), lit.span());
+
+                    exp.lit = syn::Lit::Float(float);
+                }
+            }
+        },
+        Expr::Unary(exp) if matches!(exp.op, UnOp::Neg(_)) => floatify(&mut exp.expr, name),
+        Expr::Binary(exp)
+            if matches!(
+                exp.op,
+                BinOp::Add(_) | BinOp::Sub(_) | BinOp::Mul(_) | BinOp::Div(_) | BinOp::Rem(_)
+            ) =>
+        {
+            floatify(&mut exp.left, name);
+            floatify(&mut exp.right, name);
+        },
+        Expr::Paren(exp) => floatify(&mut exp.expr, name),
+        Expr::Group(exp) => floatify(&mut exp.expr, name),
+        _ => (),
+    }
+}
+
+/// Rewrites an expression of the type `ty` so that it compiles just like what the runtime parser accepts. Unsuffixed integer literals which are values of float types become float literals, and integers which are values of non-zero types become non-zero values checked at compile time.
+pub(crate) fn rewrite(expr: &mut Expr, ty: &Type) {
+    match leaf(ty) {
+        Some(Leaf::Float(name)) => return floatify(expr, name),
+        Some(Leaf::NonZero(name)) => {
+            let message =
+                format!("value {} out of range for NonZero<{name}>", expr.to_token_stream());
+            let name: Type = syn::parse_str(&name).unwrap();
+
+            *expr = parse_quote! {
+                const {
+                    match ::core::num::NonZero::<#name>::new(#expr) {
+                        Some(n) => n,
+                        None => panic!("{}", #message),
+                    }
+                }
+            };
+
+            return;
+        },
+        None => (),
+    }
+
+    if let Some(ty) = generic_argument(ty, "Option") {
+        if let Expr::Call(exp) = expr {
+            exp.args.iter_mut().for_each(|expr| rewrite(expr, ty));
+        }
+
+        return;
+    }
+
+    match (ty, expr) {
+        (Type::Array(ty), Expr::Array(exp)) => {
+            exp.elems.iter_mut().for_each(|expr| rewrite(expr, &ty.elem))
+        },
+        (Type::Slice(ty), Expr::Array(exp)) => {
+            exp.elems.iter_mut().for_each(|expr| rewrite(expr, &ty.elem))
+        },
+        (Type::Array(ty), Expr::Repeat(exp)) => rewrite(&mut exp.expr, &ty.elem),
+        (Type::Slice(ty), Expr::Repeat(exp)) => rewrite(&mut exp.expr, &ty.elem),
+        (Type::Tuple(ty), Expr::Tuple(exp)) => {
+            exp.elems.iter_mut().zip(ty.elems.iter()).for_each(|(expr, ty)| rewrite(expr, ty))
+        },
+        (Type::Paren(ty), expr) => rewrite(expr, &ty.elem),
+        (Type::Group(ty), expr) => rewrite(expr, &ty.elem),
+        _ => (),
+    }
+}
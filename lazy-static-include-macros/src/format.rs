@@ -0,0 +1,68 @@
+use syn::{
+    Error, Expr, Ident, Token,
+    parse::{Parse, ParseStream},
+};
+
+/// The formats of data files which can be checked at compile time.
+pub(crate) enum Format {
+    #[cfg(feature = "json")]
+    Json,
+    #[cfg(feature = "toml")]
+    Toml,
+    #[cfg(feature = "yaml")]
+    Yaml,
+}
+
+impl Parse for Format {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+
+        match ident.to_string().as_str() {
+            #[cfg(feature = "json")]
+            "json" => Ok(Format::Json),
+            #[cfg(feature = "toml")]
+            "toml" => Ok(Format::Toml),
+            #[cfg(feature = "yaml")]
+            "yaml" => Ok(Format::Yaml),
+            _ => Err(Error::new(ident.span(), "unsupported format")),
+        }
+    }
+}
+
+impl Format {
+    /// Checks the syntax of a text, and returns the error message if it is incorrect.
+    pub(crate) fn check(&self, text: &str) -> Result<(), String> {
+        match *self {
+            #[cfg(feature = "json")]
+            Format::Json => serde_json::from_str::<serde_json::Value>(text)
+                .map(|_| ())
+                .map_err(|err| err.to_string()),
+            #[cfg(feature = "toml")]
+            Format::Toml => {
+                toml::from_str::<toml::Table>(text).map(|_| ()).map_err(|err| err.to_string())
+            },
+            #[cfg(feature = "yaml")]
+            Format::Yaml => serde_yaml_ng::from_str::<serde_yaml_ng::Value>(text)
+                .map(|_| ())
+                .map_err(|err| err.to_string()),
+        }
+    }
+}
+
+pub(crate) struct FormatAndPath {
+    pub(crate) format: Format,
+    pub(crate) path:   Expr,
+}
+
+impl Parse for FormatAndPath {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let format = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let path = input.parse()?;
+
+        Ok(FormatAndPath {
+            format,
+            path,
+        })
+    }
+}
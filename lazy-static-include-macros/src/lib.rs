@@ -0,0 +1,516 @@
+/*!
+# Lazy Static Include Macros
+
+This crate provides procedural macros used by the `lazy-static-include` crate to expand files at compile time. Use the `lazy-static-include` crate directly instead.
+*/
+
+mod array;
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+mod format;
+mod map;
+mod path;
+
+use std::{fs, path::Path};
+
+#[cfg(feature = "glob")]
+use glob::Pattern;
+use proc_macro::TokenStream;
+#[cfg(feature = "verify")]
+use quote::format_ident;
+use quote::quote;
+#[cfg(feature = "verify")]
+use sha2::{Digest, Sha256};
+use syn::{
+    Data, DeriveInput, Error, Expr, Fields, Ident, Token, Type,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    spanned::Spanned,
+};
+
+/// The kind of data included from files.
+enum Kind {
+    Bytes,
+    Str,
+}
+
+impl Parse for Kind {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+
+        match ident.to_string().as_str() {
+            "bytes" => Ok(Kind::Bytes),
+            "str" => Ok(Kind::Str),
+            _ => Err(Error::new(ident.span(), "expected `bytes` or `str`")),
+        }
+    }
+}
+
+struct KindAndPath {
+    kind: Kind,
+    path: Expr,
+}
+
+impl Parse for KindAndPath {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let kind = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let path = input.parse()?;
+
+        Ok(KindAndPath {
+            kind,
+            path,
+        })
+    }
+}
+
+#[cfg(feature = "verify")]
+struct NamedPath {
+    vis:  syn::Visibility,
+    name: Ident,
+    path: Expr,
+}
+
+#[cfg(feature = "verify")]
+impl Parse for NamedPath {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let path = input.parse()?;
+
+        Ok(NamedPath {
+            vis,
+            name,
+            path,
+        })
+    }
+}
+
+/// Expands a block which evaluates to the SHA-256 hash of a file in lowercase hexadecimal. The file is included into an unused constant so that the hash is computed again whenever the file is modified.
+#[cfg(feature = "verify")]
+fn hash_file(path: &Expr) -> Result<proc_macro2::TokenStream, Error> {
+    let file = path::resolve(path)?;
+
+    let data = fs::read(&file)
+        .map_err(|err| Error::new(path.span(), format!("cannot read {file:?}: {err}")))?;
+
+    let hash = format!("{:x}", Sha256::digest(data));
+    let file = path::to_str(&file, path)?;
+
+    Ok(quote! {
+        {
+            const _: &[u8] = include_bytes!(#file);
+
+            #hash
+        }
+    })
+}
+
+/// Expands an `(relative_path, include_bytes!(absolute_path))` or `(relative_path, include_str!(absolute_path))` entry.
+fn include_entry(
+    kind: &Kind,
+    relative_path: &str,
+    absolute_path: &str,
+) -> proc_macro2::TokenStream {
+    match kind {
+        Kind::Bytes => quote! { (#relative_path, include_bytes!(#absolute_path)) },
+        Kind::Str => quote! { (#relative_path, include_str!(#absolute_path)) },
+    }
+}
+
+/// Expands a static slice of entries.
+fn include_entries(kind: &Kind, entries: &[(String, String)]) -> proc_macro2::TokenStream {
+    let entries = entries
+        .iter()
+        .map(|(relative_path, absolute_path)| include_entry(kind, relative_path, absolute_path));
+
+    let ty = match kind {
+        Kind::Bytes => quote! { [u8] },
+        Kind::Str => quote! { str },
+    };
+
+    quote! {
+        {
+            const ENTRIES: &[(&str, &#ty)] = &[#(#entries),*];
+
+            ENTRIES
+        }
+    }
+}
+
+fn walk(
+    directory: &Path,
+    prefix: &str,
+    entries: &mut Vec<(String, String)>,
+    expr: &Expr,
+) -> Result<(), Error> {
+    let read_dir = fs::read_dir(directory)
+        .map_err(|err| Error::new(expr.span(), format!("cannot read {directory:?}: {err}")))?;
+
+    for entry in read_dir {
+        let entry = entry
+            .map_err(|err| Error::new(expr.span(), format!("cannot read {directory:?}: {err}")))?;
+
+        let path = entry.path();
+
+        let file_name = entry.file_name();
+        let file_name = file_name
+            .to_str()
+            .ok_or_else(|| Error::new(expr.span(), format!("{path:?} is not a UTF-8 path")))?;
+
+        let relative_path = format!("{prefix}{file_name}");
+
+        if path.is_dir() {
+            walk(&path, &format!("{relative_path}/"), entries, expr)?;
+        } else {
+            entries.push((relative_path, path::to_str(&path, expr)?));
+        }
+    }
+
+    Ok(())
+}
+
+/// Expands a directory relative to **CARGO_MANIFEST_DIR** into a static slice of `(relative_path, data)` entries sorted by their relative paths. The first argument is `bytes` or `str`.
+#[proc_macro]
+pub fn include_dir(input: TokenStream) -> TokenStream {
+    let KindAndPath {
+        kind,
+        path,
+    } = parse_macro_input!(input as KindAndPath);
+
+    let result = path::resolve(&path).and_then(|directory| {
+        if !directory.is_dir() {
+            return Err(Error::new(path.span(), format!("{directory:?} is not a directory")));
+        }
+
+        let mut entries = Vec::new();
+
+        walk(&directory, "", &mut entries, &path)?;
+
+        entries.sort();
+
+        Ok(include_entries(&kind, &entries))
+    });
+
+    match result {
+        Ok(token_stream) => token_stream.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Expands files matching a glob pattern relative to **CARGO_MANIFEST_DIR** into a static slice of `(relative_path, data)` entries sorted by their paths relative to **CARGO_MANIFEST_DIR**. The first argument is `bytes` or `str`.
+#[cfg(feature = "glob")]
+#[proc_macro]
+pub fn include_glob(input: TokenStream) -> TokenStream {
+    let KindAndPath {
+        kind,
+        path: pattern,
+    } = parse_macro_input!(input as KindAndPath);
+
+    let result = path::manifest_dir(&pattern).and_then(|manifest_dir| {
+        let manifest_dir = path::to_str(&manifest_dir, &pattern)?;
+        let relative_pattern = path::literal(&pattern)?;
+        let relative_pattern = path::to_str(&relative_pattern, &pattern)?;
+
+        let full_pattern = Path::new(&Pattern::escape(&manifest_dir)).join(&relative_pattern);
+        let full_pattern = path::to_str(&full_pattern, &pattern)?;
+
+        let paths = glob::glob(&full_pattern)
+            .map_err(|err| Error::new(pattern.span(), format!("incorrect pattern: {err}")))?;
+
+        let mut entries = Vec::new();
+
+        for path in paths {
+            let path =
+                path.map_err(|err| Error::new(pattern.span(), format!("cannot read {err}")))?;
+
+            if path.is_dir() {
+                continue;
+            }
+
+            let relative_path = path
+                .strip_prefix(&manifest_dir)
+                .unwrap_or(&path)
+                .components()
+                .map(|component| path::to_str(Path::new(component.as_os_str()), &pattern))
+                .collect::<Result<Vec<String>, Error>>()?
+                .join("/");
+
+            entries.push((relative_path, path::to_str(&path, &pattern)?));
+        }
+
+        if entries.is_empty() {
+            return Err(Error::new(
+                pattern.span(),
+                format!("no files match the pattern {relative_pattern:?}"),
+            ));
+        }
+
+        entries.sort();
+
+        Ok(include_entries(&kind, &entries))
+    });
+
+    match result {
+        Ok(token_stream) => token_stream.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Expands a file relative to **CARGO_MANIFEST_DIR** containing a rust array of the type in the first argument, which is an array type or a slice type. The elements are rewritten to be what the runtime parser accepts if their types need, such as float types and non-zero integer types.
+#[proc_macro]
+pub fn include_array(input: TokenStream) -> TokenStream {
+    let array::TypeAndPath {
+        ty,
+        path,
+    } = parse_macro_input!(input as array::TypeAndPath);
+
+    let reference = matches!(ty, Type::Slice(_)).then(|| quote! { & });
+
+    let result = path::resolve(&path).and_then(|file| {
+        let file_str = path::to_str(&file, &path)?;
+
+        // Let `include!` report errors at the exact positions in the file if nothing needs to be changed.
+        if !array::needs_rewrite(&ty) {
+            return Ok(quote! { #reference include!(#file_str) });
+        }
+
+        let text = fs::read_to_string(&file)
+            .map_err(|err| Error::new(path.span(), format!("cannot read {file:?}: {err}")))?;
+
+        let mut expr: Expr = syn::parse_str(&text)
+            .map_err(|err| Error::new(path.span(), format!("incorrect file {file:?}: {err}")))?;
+
+        array::rewrite(&mut expr, &ty);
+
+        // The file is included into an unused constant so that the array is expanded again whenever the file is modified.
+        Ok(quote! {
+            {
+                const _: &[u8] = include_bytes!(#file_str);
+
+                #reference #expr
+            }
+        })
+    });
+
+    match result {
+        Ok(token_stream) => token_stream.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Expands a file relative to **CARGO_MANIFEST_DIR** containing a rust array of `(key, value)` tuples or `key = value` lines into a `&'static [(K, V)]` slice sorted by the keys. The first argument is the type of the slice, like `[(K, V)]`.
+#[proc_macro]
+pub fn include_map(input: TokenStream) -> TokenStream {
+    let array::TypeAndPath {
+        ty,
+        path,
+    } = parse_macro_input!(input as array::TypeAndPath);
+
+    let result = path::resolve(&path).and_then(|file| {
+        let text = fs::read_to_string(&file)
+            .map_err(|err| Error::new(path.span(), format!("cannot read {file:?}: {err}")))?;
+
+        let mut expr =
+            Expr::Array(map::sorted_entries(&text).map_err(|err| {
+                Error::new(path.span(), format!("incorrect file {file:?}: {err}"))
+            })?);
+
+        array::rewrite(&mut expr, &ty);
+
+        let file = path::to_str(&file, &path)?;
+
+        // The file is included into an unused constant so that the map is expanded again whenever the file is modified.
+        Ok(quote! {
+            {
+                const _: &[u8] = include_bytes!(#file);
+
+                &#expr
+            }
+        })
+    });
+
+    match result {
+        Ok(token_stream) => token_stream.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Expands the SHA-256 hash of a file relative to **CARGO_MANIFEST_DIR** in lowercase hexadecimal.
+#[cfg(feature = "verify")]
+#[proc_macro]
+pub fn hash(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as Expr);
+
+    match hash_file(&path) {
+        Ok(token_stream) => token_stream.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Expands a `NAME_HASH` constant of the SHA-256 hash of a file relative to **CARGO_MANIFEST_DIR** in lowercase hexadecimal. The input is `visibility NAME => path`.
+#[cfg(feature = "verify")]
+#[proc_macro]
+pub fn include_hash(input: TokenStream) -> TokenStream {
+    let NamedPath {
+        vis,
+        name,
+        path,
+    } = parse_macro_input!(input as NamedPath);
+
+    match hash_file(&path) {
+        Ok(hash) => {
+            let hash_name = format_ident!("{}_HASH", name);
+            let doc = format!("The SHA-256 hash of the file of `{name}` in lowercase hexadecimal.");
+
+            quote! {
+                #[doc = #doc]
+                #vis const #hash_name: &str = #hash;
+            }
+            .into()
+        },
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Expands the deflate-compressed content of a file relative to **CARGO_MANIFEST_DIR** as a byte array (`&'static [u8]`).
+#[cfg(feature = "deflate")]
+#[proc_macro]
+pub fn include_compressed(input: TokenStream) -> TokenStream {
+    use std::io::Write;
+
+    use flate2::{Compression, write::DeflateEncoder};
+
+    let path = parse_macro_input!(input as Expr);
+
+    let result = path::resolve(&path).and_then(|file| {
+        let to_error = |err: std::io::Error| {
+            Error::new(path.span(), format!("cannot compress {file:?}: {err}"))
+        };
+
+        let data = fs::read(&file).map_err(to_error)?;
+
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(&data).map_err(to_error)?;
+        let compressed = proc_macro2::Literal::byte_string(&encoder.finish().map_err(to_error)?);
+
+        let file = path::to_str(&file, &path)?;
+
+        // The file is included into an unused constant so that the content is compressed again whenever the file is modified.
+        Ok(quote! {
+            {
+                const _: &[u8] = include_bytes!(#file);
+
+                #compressed
+            }
+        })
+    });
+
+    match result {
+        Ok(token_stream) => token_stream.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Checks the syntax of a data file relative to **CARGO_MANIFEST_DIR**, and expands `include_str!(absolute_path)` if it is correct. The first argument is the format of the file, which is `json`, `toml` or `yaml`.
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+#[proc_macro]
+pub fn include_checked(input: TokenStream) -> TokenStream {
+    let format::FormatAndPath {
+        format,
+        path,
+    } = parse_macro_input!(input as format::FormatAndPath);
+
+    let result = path::resolve(&path).and_then(|file| {
+        let text = fs::read_to_string(&file)
+            .map_err(|err| Error::new(path.span(), format!("cannot read {file:?}: {err}")))?;
+
+        format
+            .check(&text)
+            .map_err(|err| Error::new(path.span(), format!("incorrect file {file:?}: {err}")))?;
+
+        let file = path::to_str(&file, &path)?;
+
+        Ok(quote! { include_str!(#file) })
+    });
+
+    match result {
+        Ok(token_stream) => token_stream.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derives the `CsvRecord` trait of the `lazy-static-include` crate for a struct with named fields. Each field is a column whose name is the name of the field, and whose type implements `FromStr`.
+///
+/// The trait is referred to as `::lazy_static_include::CsvRecord`. If the `lazy-static-include` crate is renamed or re-exported, its path can be set by `#[csv_record(crate = path)]`.
+#[proc_macro_derive(CsvRecord, attributes(csv_record))]
+pub fn derive_csv_record(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let mut crate_path: syn::Path = syn::parse_quote!(::lazy_static_include);
+
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("csv_record")) {
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                crate_path = meta.value()?.parse()?;
+
+                Ok(())
+            } else {
+                Err(meta.error("expected `crate`"))
+            }
+        });
+
+        if let Err(err) = result {
+            return err.to_compile_error().into();
+        }
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Error::new(input.ident.span(), "expected a struct with named fields")
+                    .to_compile_error()
+                    .into();
+            },
+        },
+        _ => {
+            return Error::new(input.ident.span(), "expected a struct with named fields")
+                .to_compile_error()
+                .into();
+        },
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let columns = fields.iter().map(|field| {
+        let ident = field.ident.as_ref().unwrap().to_string();
+
+        ident.strip_prefix("r#").map(String::from).unwrap_or(ident)
+    });
+
+    let values = fields.iter().enumerate().map(|(i, field)| {
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+
+        quote! {
+            #ident: fields[#i].parse::<#ty>().map_err(|err| (#i, err.to_string()))?
+        }
+    });
+
+    quote! {
+        impl #impl_generics #crate_path::CsvRecord for #name #ty_generics #where_clause {
+            fn columns() -> &'static [&'static str] {
+                &[#(#columns),*]
+            }
+
+            fn from_fields(fields: &[&str]) -> ::std::result::Result<Self, (usize, String)> {
+                Ok(#name {
+                    #(#values),*
+                })
+            }
+        }
+    }
+    .into()
+}
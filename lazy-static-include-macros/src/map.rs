@@ -0,0 +1,223 @@
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{
+    Error, Expr, ExprArray, Lit, Token, UnOp,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned,
+};
+
+/// The value of a literal key, which is ordered just like what the key is in rust.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum Key {
+    /// A negative integer is `(false, u128::MAX - magnitude)`, and a non-negative integer is `(true, value)`.
+    Int(bool, u128),
+    Str(String),
+    ByteStr(Vec<u8>),
+    Char(char),
+    Bool(bool),
+}
+
+impl Key {
+    fn from_expr(expr: &Expr) -> Result<Key, Error> {
+        let error = || Error::new(expr.span(), "expected a literal key");
+
+        match expr {
+            Expr::Lit(exp) => match &exp.lit {
+                Lit::Int(lit) => Ok(Key::Int(true, lit.base10_parse()?)),
+                Lit::Byte(lit) => Ok(Key::Int(true, lit.value() as u128)),
+                Lit::Str(lit) => Ok(Key::Str(lit.value())),
+                Lit::ByteStr(lit) => Ok(Key::ByteStr(lit.value())),
+                Lit::Char(lit) => Ok(Key::Char(lit.value())),
+                Lit::Bool(lit) => Ok(Key::Bool(lit.value)),
+                _ => Err(error()),
+            },
+            Expr::Unary(exp) if matches!(exp.op, UnOp::Neg(_)) => {
+                match Key::from_expr(&exp.expr)? {
+                    Key::Int(true, 0) => Ok(Key::Int(true, 0)),
+                    Key::Int(true, n) => Ok(Key::Int(false, u128::MAX - n)),
+                    _ => Err(error()),
+                }
+            },
+            Expr::Paren(exp) => Key::from_expr(&exp.expr),
+            Expr::Group(exp) => Key::from_expr(&exp.expr),
+            _ => Err(error()),
+        }
+    }
+}
+
+/// A `key = value` line.
+struct Entry {
+    key:   Expr,
+    value: Expr,
+}
+
+impl Parse for Entry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Parse the key as a (possibly negated) literal so that `=` is not parsed as an assignment.
+        let key = if input.peek(Token![-]) {
+            let minus = input.parse::<Token![-]>()?;
+            let lit = input.parse::<Lit>()?;
+
+            parse_quote! { #minus #lit }
+        } else {
+            Expr::Lit(syn::ExprLit {
+                attrs: Vec::new(), lit: input.parse()?
+            })
+        };
+
+        input.parse::<Token![=]>()?;
+
+        let value = input.parse()?;
+
+        Ok(Entry {
+            key,
+            value,
+        })
+    }
+}
+
+/// Removes the doc attributes which doc comments like `/// ...` and `/** ... */` are tokenized into, because all comments are skipped by the runtime parser.
+fn strip_doc_comments(tokens: TokenStream) -> TokenStream {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut stripped = TokenStream::new();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '#' => {
+                // Inner doc comments like `//! ...` are tokenized into `#![doc = "..."]`.
+                let bang = match tokens.peek() {
+                    Some(TokenTree::Punct(bang)) if bang.as_char() == '!' => tokens.next(),
+                    _ => None,
+                };
+
+                let is_doc = match tokens.peek() {
+                    Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => {
+                        matches!(group.stream().into_iter().next(), Some(TokenTree::Ident(ident)) if ident == "doc")
+                    },
+                    _ => false,
+                };
+
+                if is_doc {
+                    tokens.next();
+                } else {
+                    stripped.extend([TokenTree::Punct(punct)]);
+                    stripped.extend(bang);
+                }
+            },
+            TokenTree::Group(group) => {
+                let mut new_group =
+                    Group::new(group.delimiter(), strip_doc_comments(group.stream()));
+
+                new_group.set_span(group.span());
+
+                stripped.extend([TokenTree::Group(new_group)]);
+            },
+            token => stripped.extend([token]),
+        }
+    }
+
+    stripped
+}
+
+/// Skips the leading whitespaces and comments of a text, just like the runtime parser does.
+fn skip_trivia(mut text: &str) -> &str {
+    loop {
+        text = text.trim_start();
+
+        if text.starts_with("//") {
+            text = text.find('\n').map_or("", |i| &text[i..]);
+        } else if text.starts_with("/*") {
+            let mut depth = 0usize;
+
+            loop {
+                if text.starts_with("/*") {
+                    depth += 1;
+                    text = &text[2..];
+                } else if text.starts_with("*/") {
+                    depth -= 1;
+                    text = &text[2..];
+
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    let mut chars = text.chars();
+
+                    if chars.next().is_none() {
+                        return text;
+                    }
+
+                    text = chars.as_str();
+                }
+            }
+        } else {
+            return text;
+        }
+    }
+}
+
+/// Parses the `(key, value)` expressions of a text containing a rust array of tuples or `key = value` lines.
+fn parse_entries(text: &str) -> Result<Vec<(Expr, Expr)>, String> {
+    if !skip_trivia(text).starts_with('[') {
+        let mut entries = Vec::new();
+
+        // Lines are tokenized one by one, because line breaks separate the entries.
+        for (i, line) in text.lines().enumerate() {
+            let tokens = strip_doc_comments(
+                line.parse().map_err(|err| format!("incorrect line {}: {err}", i + 1))?,
+            );
+
+            if tokens.is_empty() {
+                continue;
+            }
+
+            let entry: Entry =
+                syn::parse2(tokens).map_err(|err| format!("incorrect line {}: {err}", i + 1))?;
+
+            entries.push((entry.key, entry.value));
+        }
+
+        return Ok(entries);
+    }
+
+    let array: ExprArray = syn::parse2(strip_doc_comments(
+        text.parse().map_err(|err: proc_macro2::LexError| err.to_string())?,
+    ))
+    .map_err(|err| err.to_string())?;
+
+    array
+        .elems
+        .into_iter()
+        .map(|expr| match expr {
+            Expr::Tuple(tuple) if tuple.elems.len() == 2 => {
+                let mut elems = tuple.elems.into_iter();
+
+                Ok((elems.next().unwrap(), elems.next().unwrap()))
+            },
+            expr => {
+                Err(format!("expected a `(key, value)` tuple, found `{}`", expr.to_token_stream()))
+            },
+        })
+        .collect()
+}
+
+/// Parses a text containing a rust array of `(key, value)` tuples or `key = value` lines into an array of tuples sorted by their keys.
+pub(crate) fn sorted_entries(text: &str) -> Result<ExprArray, String> {
+    let mut entries = parse_entries(text)?
+        .into_iter()
+        .map(|(key, value)| Ok((Key::from_expr(&key).map_err(|err| err.to_string())?, key, value)))
+        .collect::<Result<Vec<(Key, Expr, Expr)>, String>>()?;
+
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    if let Some(pair) = entries.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        return Err(format!("duplicate key `{}`", pair[1].1.to_token_stream()));
+    }
+
+    let entries = entries.into_iter().map(|(_, key, value)| -> Expr {
+        parse_quote! { (#key, #value) }
+    });
+
+    Ok(parse_quote! { [#(#entries),*] })
+}
@@ -0,0 +1,56 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use syn::{Error, Expr, Lit, spanned::Spanned};
+
+/// Joins the literal strings of a (possibly nested tuple) path expression, just like the `manifest_dir_macros` crate does.
+fn join(expr: &Expr, path: &mut PathBuf) -> Result<(), Error> {
+    match expr {
+        Expr::Lit(exp) => match &exp.lit {
+            Lit::Str(s) => {
+                path.push(s.value());
+
+                Ok(())
+            },
+            _ => Err(Error::new(expr.span(), "expected a literal string")),
+        },
+        Expr::Tuple(tuple) => tuple.elems.iter().try_for_each(|expr| join(expr, path)),
+        Expr::Paren(exp) => join(&exp.expr, path),
+        Expr::Group(exp) => join(&exp.expr, path),
+        _ => {
+            Err(Error::new(expr.span(), "expected a literal string or a tuple of literal strings"))
+        },
+    }
+}
+
+/// Gets the directory containing the manifest of the package being compiled.
+pub(crate) fn manifest_dir(expr: &Expr) -> Result<PathBuf, Error> {
+    env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .ok_or_else(|| Error::new(expr.span(), "we need CARGO_MANIFEST_DIR"))
+}
+
+/// Joins the literal strings of a path expression without resolving it.
+pub(crate) fn literal(expr: &Expr) -> Result<PathBuf, Error> {
+    let mut path = PathBuf::new();
+
+    join(expr, &mut path)?;
+
+    Ok(path)
+}
+
+/// Resolves a path expression relative to the directory containing the manifest of the package being compiled.
+pub(crate) fn resolve(expr: &Expr) -> Result<PathBuf, Error> {
+    let path = literal(expr)?;
+
+    if path.is_absolute() { Ok(path) } else { Ok(manifest_dir(expr)?.join(path)) }
+}
+
+/// Converts a path into a string which can be used in the `include_bytes` and `include_str` macros.
+pub(crate) fn to_str(path: &Path, expr: &Expr) -> Result<String, Error> {
+    path.to_str()
+        .map(String::from)
+        .ok_or_else(|| Error::new(expr.span(), format!("{path:?} is not a UTF-8 path")))
+}
@@ -0,0 +1,344 @@
+use std::{any, num::NonZero};
+
+use crate::{
+    ArrayIncludeError,
+    eval::{self, EvalError, Ty, Value},
+    expr::{self, Expr, ExprKind, Lit},
+    load::read_text,
+};
+
+/// Types which can be the element type of arrays included by the `lazy_static_include_array` macro.
+///
+/// Implement this trait for your own types so that their values can be parsed from files when the files are loaded at runtime. When the files are embedded, they are included by the `include!` macro, so the paths in them need to be resolvable where the `lazy_static_include_array` macro is used.
+pub trait IncludeElement: Sized {
+    /// The name of the type shown in error messages.
+    #[inline]
+    fn type_name() -> &'static str {
+        any::type_name::<Self>()
+    }
+
+    /// Converts the expression of the element at `index` into a value.
+    fn from_expr(expr: &Expr, index: usize) -> Result<Self, ArrayIncludeError>;
+
+    /// Creates an `ArrayIncludeError::WrongElementType` error for the expression `expr` of the element at `index`.
+    #[inline]
+    fn wrong_element_type(expr: &Expr, index: usize) -> ArrayIncludeError {
+        wrong_element_type(expr, index, Self::type_name())
+    }
+}
+
+/// Creates an `ArrayIncludeError::WrongElementType` error for the element `expr`.
+pub fn wrong_element_type(expr: &Expr, index: usize, expected: &'static str) -> ArrayIncludeError {
+    ArrayIncludeError::WrongElementType {
+        index,
+        expected,
+        found_token: expr.text.to_string(),
+        line: expr.line,
+        column: expr.column,
+    }
+}
+
+/// Converts an `EvalError` error of the element at `index` into an `ArrayIncludeError` error.
+fn eval_error(err: EvalError, index: usize) -> ArrayIncludeError {
+    match err {
+        EvalError::WrongType {
+            expr,
+            expected,
+        } => wrong_element_type(expr, index, expected.name()),
+        EvalError::Overflow {
+            expr,
+            ty,
+        } => ArrayIncludeError::Overflow {
+            index,
+            value: expr.text.to_string(),
+            expected: ty.name(),
+            line: expr.line,
+            column: expr.column,
+        },
+    }
+}
+
+/// Gets the length of an array expression of the element at `index` without expanding array repeat expressions like `[0u8; 16]`. The error `not_array` is returned if the expression is not an array.
+fn array_length(
+    expr: &Expr,
+    index: usize,
+    not_array: impl FnOnce() -> ArrayIncludeError,
+) -> Result<usize, ArrayIncludeError> {
+    match &expr.kind {
+        ExprKind::Array(array) => Ok(array.len()),
+        ExprKind::Repeat(_, length) => usize::from_expr(length, index),
+        _ => Err(not_array()),
+    }
+}
+
+/// Allocates a vector for `length` elements of the array expression `expr`. A length which is too large to be allocated is an error rather than an abort.
+fn with_capacity<T>(expr: &Expr, length: usize) -> Result<Vec<T>, ArrayIncludeError> {
+    let mut vec = Vec::new();
+
+    vec.try_reserve_exact(length).map_err(|_| ArrayIncludeError::TooLong {
+        length,
+        line: expr.line,
+        column: expr.column,
+    })?;
+
+    Ok(vec)
+}
+
+/// Gets the `length` elements of an array expression, whose length has been checked by `array_length`, repeating the element of an array repeat expression.
+fn array_elements<'a, 'e>(
+    expr: &'a Expr<'e>,
+    length: usize,
+) -> Result<Vec<&'a Expr<'e>>, ArrayIncludeError> {
+    match &expr.kind {
+        ExprKind::Array(array) => Ok(array.iter().collect()),
+        ExprKind::Repeat(element, _) => {
+            let mut elements = with_capacity(expr, length)?;
+
+            elements.resize(length, &**element);
+
+            Ok(elements)
+        },
+        _ => unreachable!(),
+    }
+}
+
+/// Converts the elements of an array expression into values.
+fn convert<T: IncludeElement>(
+    expr: &Expr,
+    elements: Vec<&Expr>,
+    index: impl Fn(usize) -> usize,
+) -> Result<Vec<T>, ArrayIncludeError> {
+    let mut values = with_capacity(expr, elements.len())?;
+
+    for (i, element) in elements.into_iter().enumerate() {
+        values.push(T::from_expr(element, index(i))?);
+    }
+
+    Ok(values)
+}
+
+macro_rules! impl_number {
+    ($($t:ident => $v:ident),* $(,)*) => {
+        $(
+            impl IncludeElement for $t {
+                fn from_expr(expr: &Expr, index: usize) -> Result<Self, ArrayIncludeError> {
+                    // The value is already in the range of the type.
+                    match eval::eval(expr, Ty::$v).map_err(|err| eval_error(err, index))? {
+                        Value::Signed(n) => Ok(n as $t),
+                        Value::Unsigned(n) => Ok(n as $t),
+                        Value::Float(f) => Ok(f as $t),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(
+    isize => Isize, i8 => I8, i16 => I16, i32 => I32, i64 => I64, i128 => I128,
+    usize => Usize, u8 => U8, u16 => U16, u32 => U32, u64 => U64, u128 => U128,
+    f32 => F32, f64 => F64,
+);
+
+macro_rules! impl_non_zero {
+    ($($t:ident),* $(,)*) => {
+        $(
+            impl IncludeElement for NonZero<$t> {
+                #[inline]
+                fn type_name() -> &'static str {
+                    concat!("NonZero<", stringify!($t), ">")
+                }
+
+                fn from_expr(expr: &Expr, index: usize) -> Result<Self, ArrayIncludeError> {
+                    // Zero is out of the range of non-zero integers.
+                    NonZero::new($t::from_expr(expr, index)?).ok_or_else(|| ArrayIncludeError::Overflow {
+                        index,
+                        value: expr.text.to_string(),
+                        expected: Self::type_name(),
+                        line: expr.line,
+                        column: expr.column,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero!(isize, i8, i16, i32, i64, i128, usize, u8, u16, u32, u64, u128);
+
+impl IncludeElement for char {
+    fn from_expr(expr: &Expr, index: usize) -> Result<Self, ArrayIncludeError> {
+        match &expr.kind {
+            ExprKind::Lit(Lit::Char(c)) => Ok(*c),
+            _ => Err(wrong_element_type(expr, index, Self::type_name())),
+        }
+    }
+}
+
+impl IncludeElement for bool {
+    fn from_expr(expr: &Expr, index: usize) -> Result<Self, ArrayIncludeError> {
+        match &expr.kind {
+            ExprKind::Lit(Lit::Bool(b)) => Ok(*b),
+            _ => Err(wrong_element_type(expr, index, Self::type_name())),
+        }
+    }
+}
+
+impl IncludeElement for &'static str {
+    fn from_expr(expr: &Expr, index: usize) -> Result<Self, ArrayIncludeError> {
+        match &expr.kind {
+            // Leak each string to get a `&'static str` reference, because the data needs to live as long as the program anyway.
+            ExprKind::Lit(Lit::Str(s)) => Ok(s.clone().leak()),
+            _ => Err(wrong_element_type(expr, index, Self::type_name())),
+        }
+    }
+}
+
+impl IncludeElement for &'static [u8] {
+    fn from_expr(expr: &Expr, index: usize) -> Result<Self, ArrayIncludeError> {
+        // Leak each byte string to get a `&'static [u8]` reference, because the data needs to live as long as the program anyway.
+        match &expr.kind {
+            ExprKind::Lit(Lit::ByteStr(s)) => Ok(s.clone().leak()),
+            ExprKind::Reference(exp) => {
+                let length = array_length(exp, index, || Self::wrong_element_type(expr, index))?;
+                let bytes = convert::<u8>(exp, array_elements(exp, length)?, |_| index)?;
+
+                Ok(bytes.leak())
+            },
+            _ => Err(wrong_element_type(expr, index, Self::type_name())),
+        }
+    }
+}
+
+/// Checks whether a path is the `variant` variant of `Option`, like `Some`, `Option::Some` or `std::option::Option::Some`.
+fn is_option_variant(segments: &[&str], variant: &str) -> bool {
+    match segments {
+        [prefix @ .., last] if *last == variant => {
+            matches!(prefix, [] | ["Option"] | ["std" | "core", "option", "Option"])
+        },
+        _ => false,
+    }
+}
+
+impl<T: IncludeElement> IncludeElement for Option<T> {
+    fn from_expr(expr: &Expr, index: usize) -> Result<Self, ArrayIncludeError> {
+        match &expr.kind {
+            ExprKind::Path(segments) if is_option_variant(segments, "None") => Ok(None),
+            ExprKind::Call(segments, args)
+                if args.len() == 1 && is_option_variant(segments, "Some") =>
+            {
+                T::from_expr(&args[0], index).map(Some)
+            },
+            _ => Err(wrong_element_type(expr, index, Self::type_name())),
+        }
+    }
+}
+
+impl<T: IncludeElement, const N: usize> IncludeElement for [T; N] {
+    fn from_expr(expr: &Expr, index: usize) -> Result<Self, ArrayIncludeError> {
+        let length = array_length(expr, index, || Self::wrong_element_type(expr, index))?;
+
+        // Every dimension must have exactly the declared length, which is checked before array repeat expressions are expanded. Errors of inner elements are reported with the index of the outermost element.
+        if length != N {
+            return Err(ArrayIncludeError::WrongInnerLength {
+                index,
+                expected: N,
+                found: length,
+                line: expr.line,
+                column: expr.column,
+            });
+        }
+
+        let elements = convert::<T>(expr, array_elements(expr, length)?, |_| index)?;
+
+        Ok(elements.try_into().unwrap_or_else(|_| unreachable!()))
+    }
+}
+
+macro_rules! impl_tuple {
+    ($(($($t:ident),+)),* $(,)*) => {
+        $(
+            impl<$($t: IncludeElement),+> IncludeElement for ($($t,)+) {
+                fn from_expr(expr: &Expr, index: usize) -> Result<Self, ArrayIncludeError> {
+                    match &expr.kind {
+                        // Errors of inner elements are reported with the index of the outermost element.
+                        ExprKind::Tuple(tuple) if tuple.len() == [$(stringify!($t)),+].len() => {
+                            let mut elems = tuple.iter();
+
+                            Ok(($($t::from_expr(elems.next().unwrap(), index)?,)+))
+                        },
+                        _ => Err(wrong_element_type(expr, index, Self::type_name())),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H),
+    (A, B, C, D, E, F, G, H, I),
+    (A, B, C, D, E, F, G, H, I, J),
+    (A, B, C, D, E, F, G, H, I, J, K),
+    (A, B, C, D, E, F, G, H, I, J, K, L),
+);
+
+/// Gets the length of the outermost array. Errors of the length of an array repeat expression are reported with the index `0`.
+fn outer_length(expr: &Expr) -> Result<usize, ArrayIncludeError> {
+    array_length(expr, 0, || ArrayIncludeError::Syntax {
+        line:    expr.line,
+        column:  expr.column,
+        message: String::from("expected an array"),
+    })
+}
+
+/// Parses a text containing a rust array.
+pub fn parse_array<T: IncludeElement, const N: usize>(
+    text: &str,
+) -> Result<[T; N], ArrayIncludeError> {
+    let expr = expr::parse(text)?;
+
+    // The length must be exactly the declared one, just like what `include!` requires. It is checked before array repeat expressions are expanded.
+    let found = outer_length(&expr)?;
+
+    if found != N {
+        return Err(ArrayIncludeError::WrongLength {
+            expected: N,
+            found,
+        });
+    }
+
+    let elements = convert::<T>(&expr, array_elements(&expr, found)?, |i| i)?;
+
+    Ok(elements.try_into().unwrap_or_else(|_| unreachable!()))
+}
+
+/// Parses a text containing a rust array into a slice.
+pub fn parse_slice<T: IncludeElement>(text: &str) -> Result<&'static [T], ArrayIncludeError> {
+    let expr = expr::parse(text)?;
+    let length = outer_length(&expr)?;
+
+    // Leak the elements to get a `&'static [T]` reference, because the data needs to live as long as the program anyway.
+    Ok(convert::<T>(&expr, array_elements(&expr, length)?, |i| i)?.leak())
+}
+
+/// Reads a file containing a rust array.
+pub fn load_array<T: IncludeElement, const N: usize>(
+    path: &'static str,
+) -> Result<[T; N], ArrayIncludeError> {
+    parse_array(&read_text(path)?)
+}
+
+/// Reads a file containing a rust array into a slice.
+pub fn load_slice<T: IncludeElement>(
+    path: &'static str,
+) -> Result<&'static [T], ArrayIncludeError> {
+    parse_slice(&read_text(path)?)
+}
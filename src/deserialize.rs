@@ -0,0 +1,70 @@
+use std::fmt::Display;
+
+use serde::de::DeserializeOwned;
+
+use crate::load::read_text;
+
+/// Deserializes a text read from `path`, panicking with the position of the error if it fails.
+fn deserialize<T: DeserializeOwned, E: Display>(
+    text: &str,
+    path: &str,
+    from_str: fn(&str) -> Result<T, E>,
+) -> T {
+    match from_str(text) {
+        Ok(value) => value,
+        Err(err) => panic!("{}, file: {}", err, path),
+    }
+}
+
+/// Reads a file which is deserialized by `from_str`.
+fn load<T: DeserializeOwned, E: Display>(
+    path: &'static str,
+    from_str: fn(&str) -> Result<T, E>,
+) -> T {
+    match read_text(path) {
+        Ok(text) => deserialize(&text, path, from_str),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[cfg(feature = "json")]
+/// Deserializes a JSON text read from `path`.
+#[inline]
+pub fn from_json<T: DeserializeOwned>(text: &str, path: &str) -> T {
+    deserialize(text, path, |text| serde_json::from_str(text))
+}
+
+#[cfg(feature = "json")]
+/// Reads a JSON file.
+#[inline]
+pub fn load_json<T: DeserializeOwned>(path: &'static str) -> T {
+    load(path, |text| serde_json::from_str(text))
+}
+
+#[cfg(feature = "toml")]
+/// Deserializes a TOML text read from `path`.
+#[inline]
+pub fn from_toml<T: DeserializeOwned>(text: &str, path: &str) -> T {
+    deserialize(text, path, |text| toml::from_str(text))
+}
+
+#[cfg(feature = "toml")]
+/// Reads a TOML file.
+#[inline]
+pub fn load_toml<T: DeserializeOwned>(path: &'static str) -> T {
+    load(path, |text| toml::from_str(text))
+}
+
+#[cfg(feature = "yaml")]
+/// Deserializes a YAML text read from `path`.
+#[inline]
+pub fn from_yaml<T: DeserializeOwned>(text: &str, path: &str) -> T {
+    deserialize(text, path, |text| serde_yaml_ng::from_str(text))
+}
+
+#[cfg(feature = "yaml")]
+/// Reads a YAML file.
+#[inline]
+pub fn load_yaml<T: DeserializeOwned>(path: &'static str) -> T {
+    load(path, |text| serde_yaml_ng::from_str(text))
+}
@@ -0,0 +1,182 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "glob")]
+use glob::Pattern;
+
+use crate::IncludeError;
+
+/// Leaks a path to get a `&'static str` reference for an `IncludeError` error.
+#[inline]
+fn leak_path(path: &Path) -> &'static str {
+    path.to_string_lossy().into_owned().leak()
+}
+
+#[inline]
+fn io_error(path: &Path, err: std::io::Error) -> IncludeError {
+    IncludeError::Io {
+        path: leak_path(path), kind: err.kind()
+    }
+}
+
+/// Gets the name of a file as a `str`, which needs to be UTF-8 so that it can be a part of a key, just like what the embedded mode requires.
+fn file_name(path: &Path) -> &str {
+    match path.file_name().and_then(|name| name.to_str()) {
+        Some(name) => name,
+        None => panic!("{path:?} is not a UTF-8 path"),
+    }
+}
+
+/// Leaks the data of a utf8-encoded file read from `path` to get a `&'static str` reference.
+fn leak_str(path: &Path, data: Vec<u8>) -> Result<&'static str, IncludeError> {
+    String::from_utf8(data).map(|s| &*s.leak()).map_err(|err| IncludeError::Utf8 {
+        path:        leak_path(path),
+        valid_up_to: err.utf8_error().valid_up_to(),
+    })
+}
+
+fn walk(
+    directory: &Path,
+    prefix: &str,
+    files: &mut Vec<(String, PathBuf, Vec<u8>)>,
+) -> Result<(), IncludeError> {
+    for entry in fs::read_dir(directory).map_err(|err| io_error(directory, err))? {
+        let path = entry.map_err(|err| io_error(directory, err))?.path();
+
+        let relative_path = format!("{prefix}{}", file_name(&path));
+
+        if path.is_dir() {
+            walk(&path, &format!("{relative_path}/"), files)?;
+        } else {
+            let data = fs::read(&path).map_err(|err| io_error(&path, err))?;
+
+            files.push((relative_path, path, data));
+        }
+    }
+
+    Ok(())
+}
+
+#[inline]
+fn walk_dir(path: &str) -> Result<Vec<(String, PathBuf, Vec<u8>)>, IncludeError> {
+    let mut files = Vec::new();
+
+    walk(Path::new(path), "", &mut files)?;
+
+    Ok(files)
+}
+
+/// Reads all files in a directory recursively. The keys are the paths relative to the directory, separated by `/`.
+pub fn load_dir_bytes(path: &str) -> BTreeMap<&'static str, &'static [u8]> {
+    let files = walk_dir(path).unwrap_or_else(|err| panic!("{err}"));
+
+    // Leak the paths and the file contents to get `&'static` references, because the data needs to live as long as the program anyway.
+    files
+        .into_iter()
+        .map(|(relative_path, _, data)| (&*relative_path.leak(), &*data.leak()))
+        .collect()
+}
+
+/// Reads all utf8-encoded files in a directory recursively. The keys are the paths relative to the directory, separated by `/`.
+pub fn load_dir_str(path: &str) -> BTreeMap<&'static str, &'static str> {
+    // Leak the paths and the file contents to get `&'static` references, because the data needs to live as long as the program anyway.
+    walk_dir(path)
+        .and_then(|files| {
+            files
+                .into_iter()
+                .map(|(relative_path, path, data)| {
+                    Ok((&*relative_path.leak(), leak_str(&path, data)?))
+                })
+                .collect()
+        })
+        .unwrap_or_else(|err| panic!("{err}"))
+}
+
+#[cfg(feature = "glob")]
+fn glob_files(
+    manifest_dir: &str,
+    pattern: &str,
+) -> Result<Vec<(String, PathBuf, Vec<u8>)>, IncludeError> {
+    let full_pattern = Path::new(&Pattern::escape(manifest_dir)).join(pattern);
+
+    let paths = match glob::glob(&full_pattern.to_string_lossy()) {
+        Ok(paths) => paths,
+        Err(err) => panic!("incorrect pattern {pattern:?}: {err}"),
+    };
+
+    let mut files = Vec::new();
+
+    for path in paths {
+        let path = path.map_err(|err| {
+            let kind = err.error().kind();
+
+            IncludeError::Io {
+                path: leak_path(err.path()),
+                kind,
+            }
+        })?;
+
+        if path.is_dir() {
+            continue;
+        }
+
+        let relative_path = path
+            .strip_prefix(manifest_dir)
+            .unwrap_or(&path)
+            .components()
+            .map(|component| match component.as_os_str().to_str() {
+                Some(component) => component,
+                None => panic!("{path:?} is not a UTF-8 path"),
+            })
+            .collect::<Vec<&str>>()
+            .join("/");
+
+        let data = fs::read(&path).map_err(|err| io_error(&path, err))?;
+
+        files.push((relative_path, path, data));
+    }
+
+    if files.is_empty() {
+        panic!("no files match the pattern {pattern:?}");
+    }
+
+    files.sort();
+
+    Ok(files)
+}
+
+#[cfg(feature = "glob")]
+/// Reads all files matching a glob pattern relative to `manifest_dir`. The paths of the entries are relative to `manifest_dir`, separated by `/`.
+pub fn load_glob_bytes(
+    manifest_dir: &str,
+    pattern: &str,
+) -> &'static [(&'static str, &'static [u8])] {
+    let files = glob_files(manifest_dir, pattern).unwrap_or_else(|err| panic!("{err}"));
+
+    // Leak the paths and the file contents to get `&'static` references, because the data needs to live as long as the program anyway.
+    files
+        .into_iter()
+        .map(|(relative_path, _, data)| (&*relative_path.leak(), &*data.leak()))
+        .collect::<Vec<_>>()
+        .leak()
+}
+
+#[cfg(feature = "glob")]
+/// Reads all utf8-encoded files matching a glob pattern relative to `manifest_dir`. The paths of the entries are relative to `manifest_dir`, separated by `/`.
+pub fn load_glob_str(manifest_dir: &str, pattern: &str) -> &'static [(&'static str, &'static str)] {
+    // Leak the paths and the file contents to get `&'static` references, because the data needs to live as long as the program anyway.
+    glob_files(manifest_dir, pattern)
+        .and_then(|files| {
+            files
+                .into_iter()
+                .map(|(relative_path, path, data)| {
+                    Ok((&*relative_path.leak(), leak_str(&path, data)?))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .unwrap_or_else(|err| panic!("{err}"))
+        .leak()
+}
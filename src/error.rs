@@ -0,0 +1,227 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io,
+};
+
+/// Errors which can occur when the data of a `try` or `verify` item is loaded from its file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IncludeError {
+    /// The file cannot be read.
+    Io { path: &'static str, kind: io::ErrorKind },
+    /// The file is not utf8-encoded. `valid_up_to` is the byte offset of the first invalid byte.
+    Utf8 { path: &'static str, valid_up_to: usize },
+}
+
+impl IncludeError {
+    /// The resolved path of the file.
+    #[inline]
+    pub const fn path(&self) -> &'static str {
+        match self {
+            IncludeError::Io {
+                path, ..
+            }
+            | IncludeError::Utf8 {
+                path, ..
+            } => path,
+        }
+    }
+}
+
+impl Display for IncludeError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            IncludeError::Io {
+                path,
+                kind,
+            } => f.write_fmt(format_args!("cannot read the file {path:?}: {kind}")),
+            IncludeError::Utf8 {
+                path,
+                valid_up_to,
+            } => f.write_fmt(format_args!(
+                "the file {path:?} is not utf8-encoded, invalid byte at offset {valid_up_to}"
+            )),
+        }
+    }
+}
+
+impl Error for IncludeError {}
+
+/// Errors which can occur when an array or a map is loaded from its file by the `lazy_static_include_array` or `lazy_static_include_map` macro.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArrayIncludeError {
+    /// The file cannot be read as a utf8-encoded text.
+    Read(IncludeError),
+    /// The number of elements is not the declared length.
+    WrongLength { expected: usize, found: usize },
+    /// An inner array of the element at `index` does not have the declared length of its dimension.
+    WrongInnerLength {
+        index:    usize,
+        expected: usize,
+        found:    usize,
+        line:     usize,
+        column:   usize,
+    },
+    /// An element is not a literal of the declared element type.
+    WrongElementType {
+        index:       usize,
+        expected:    &'static str,
+        found_token: String,
+        line:        usize,
+        column:      usize,
+    },
+    /// A value is out of the range of its type, such as a negative value of an unsigned type.
+    Overflow {
+        index:    usize,
+        value:    String,
+        expected: &'static str,
+        line:     usize,
+        column:   usize,
+    },
+    /// An array repeat expression like `[0u8; 16]` is too long to be allocated.
+    TooLong { length: usize, line: usize, column: usize },
+    /// The text is not a rust array.
+    Syntax { line: usize, column: usize, message: String },
+    /// A key of a map appears more than once.
+    DuplicateKey { key: String, line: usize, column: usize },
+}
+
+impl From<IncludeError> for ArrayIncludeError {
+    #[inline]
+    fn from(error: IncludeError) -> Self {
+        ArrayIncludeError::Read(error)
+    }
+}
+
+impl Display for ArrayIncludeError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ArrayIncludeError::Read(err) => Display::fmt(err, f),
+            ArrayIncludeError::WrongLength {
+                expected,
+                found,
+            } => f.write_fmt(format_args!(
+                "incorrect length, expected {expected} elements but found {found}"
+            )),
+            ArrayIncludeError::WrongInnerLength {
+                index,
+                expected,
+                found,
+                line,
+                column,
+            } => f.write_fmt(format_args!(
+                "incorrect length of an inner array, index = {index} (line {line}, column \
+                 {column}), expected {expected} elements but found {found}"
+            )),
+            ArrayIncludeError::WrongElementType {
+                index,
+                expected,
+                found_token,
+                line,
+                column,
+            } => f.write_fmt(format_args!(
+                "incorrect element type, index = {index} (line {line}, column {column}), expected \
+                 `{expected}` but found `{found_token}`"
+            )),
+            ArrayIncludeError::Overflow {
+                index,
+                value,
+                expected,
+                line,
+                column,
+            } => f.write_fmt(format_args!(
+                "value {value} out of range for {expected} at index {index} (line {line}, col \
+                 {column})"
+            )),
+            ArrayIncludeError::TooLong {
+                length,
+                line,
+                column,
+            } => f.write_fmt(format_args!(
+                "array length {length} is too large to be allocated (line {line}, column {column})"
+            )),
+            ArrayIncludeError::Syntax {
+                line,
+                column,
+                message,
+            } => f.write_fmt(format_args!(
+                "incorrect array (line {line}, column {column}): {message}"
+            )),
+            ArrayIncludeError::DuplicateKey {
+                key,
+                line,
+                column,
+            } => f.write_fmt(format_args!("duplicate key {key} (line {line}, column {column})")),
+        }
+    }
+}
+
+impl Error for ArrayIncludeError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ArrayIncludeError::Read(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+/// Errors which can occur when a table is loaded from its file by the `lazy_static_include_csv` macro.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CsvIncludeError {
+    /// The file cannot be read as a utf8-encoded text.
+    Read(IncludeError),
+    /// The text is not a CSV table.
+    Syntax { line: u64, message: String },
+    /// A column of the record type is not in the header row.
+    MissingColumn { column: &'static str },
+    /// A field cannot be converted into the type of its column.
+    WrongField { line: u64, column: &'static str, value: String, message: String },
+}
+
+#[cfg(feature = "csv")]
+impl From<IncludeError> for CsvIncludeError {
+    #[inline]
+    fn from(error: IncludeError) -> Self {
+        CsvIncludeError::Read(error)
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Display for CsvIncludeError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvIncludeError::Read(err) => Display::fmt(err, f),
+            CsvIncludeError::Syntax {
+                line,
+                message,
+            } => f.write_fmt(format_args!("incorrect CSV (line {line}): {message}")),
+            CsvIncludeError::MissingColumn {
+                column,
+            } => f.write_fmt(format_args!("the column `{column}` is not in the header row")),
+            CsvIncludeError::WrongField {
+                line,
+                column,
+                value,
+                message,
+            } => f.write_fmt(format_args!(
+                "incorrect field {value:?} (line {line}, column `{column}`): {message}"
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Error for CsvIncludeError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvIncludeError::Read(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,475 @@
+use crate::expr::{BinOp, Expr, ExprKind, Lit};
+
+macro_rules! tys {
+    ($($v:ident => $t:ident),* $(,)*) => {
+        /// Numeric primitive types.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum Ty {
+            $($v,)*
+        }
+
+        impl Ty {
+            /// Gets the type of a primitive type name, like `u8`.
+            pub fn from_name(name: &str) -> Option<Ty> {
+                match name {
+                    $(stringify!($t) => Some(Ty::$v),)*
+                    _ => None,
+                }
+            }
+
+            /// The name of the type, like `u8`.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Ty::$v => stringify!($t),)*
+                }
+            }
+
+            fn bits(self) -> u32 {
+                match self {
+                    $(Ty::$v => (size_of::<$t>() * 8) as u32,)*
+                }
+            }
+        }
+    };
+}
+
+tys! {
+    I8 => i8,
+    I16 => i16,
+    I32 => i32,
+    I64 => i64,
+    I128 => i128,
+    Isize => isize,
+    U8 => u8,
+    U16 => u16,
+    U32 => u32,
+    U64 => u64,
+    U128 => u128,
+    Usize => usize,
+    F32 => f32,
+    F64 => f64,
+}
+
+impl Ty {
+    #[inline]
+    fn is_float(self) -> bool {
+        matches!(self, Ty::F32 | Ty::F64)
+    }
+
+    #[inline]
+    fn is_signed(self) -> bool {
+        matches!(self, Ty::I8 | Ty::I16 | Ty::I32 | Ty::I64 | Ty::I128 | Ty::Isize)
+    }
+
+    /// The minimum and the maximum values of a signed integer type.
+    #[inline]
+    fn signed_range(self) -> (i128, i128) {
+        let max = (u128::MAX >> (129 - self.bits())) as i128;
+
+        (-max - 1, max)
+    }
+
+    /// The maximum value of an unsigned integer type.
+    #[inline]
+    fn unsigned_max(self) -> u128 {
+        u128::MAX >> (128 - self.bits())
+    }
+}
+
+/// A value of a numeric primitive type. Signed integers are `Signed`, unsigned integers are `Unsigned`, and `f32` values are stored exactly as `f64` ones.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+}
+
+/// Errors which can occur when an expression is evaluated. Each of them refers to the sub-expression causing it.
+#[derive(Debug, Clone, Copy)]
+pub enum EvalError<'e> {
+    /// The expression cannot be a value of the expected type.
+    WrongType { expr: &'e Expr<'e>, expected: Ty },
+    /// The value of the expression is out of the range of the type `ty`, or the operation would panic.
+    Overflow { expr: &'e Expr<'e>, ty: Ty },
+}
+
+/// Converts an integer into the type `ty` if it is in the range of the type.
+fn fit(ty: Ty, value: Value) -> Option<Value> {
+    if ty.is_signed() {
+        let n = match value {
+            Value::Signed(n) => n,
+            Value::Unsigned(n) => i128::try_from(n).ok()?,
+            Value::Float(_) => return None,
+        };
+
+        let (min, max) = ty.signed_range();
+
+        (min..=max).contains(&n).then_some(Value::Signed(n))
+    } else {
+        let n = match value {
+            Value::Signed(n) => u128::try_from(n).ok()?,
+            Value::Unsigned(n) => n,
+            Value::Float(_) => return None,
+        };
+
+        (n <= ty.unsigned_max()).then_some(Value::Unsigned(n))
+    }
+}
+
+/// Truncates the bits of an integer to the width of the integer type `ty`, like what `as` does.
+fn truncate(ty: Ty, bits: u128) -> Value {
+    let shift = 128 - ty.bits();
+
+    if ty.is_signed() {
+        Value::Signed(((bits << shift) as i128) >> shift)
+    } else {
+        Value::Unsigned((bits << shift) >> shift)
+    }
+}
+
+/// Converts a value into the type `ty` with the semantics of `as`.
+fn cast(value: Value, ty: Ty) -> Value {
+    match (value, ty) {
+        (Value::Signed(n), Ty::F32) => Value::Float(n as f32 as f64),
+        (Value::Unsigned(n), Ty::F32) => Value::Float(n as f32 as f64),
+        (Value::Float(f), Ty::F32) => Value::Float(f as f32 as f64),
+        (Value::Signed(n), Ty::F64) => Value::Float(n as f64),
+        (Value::Unsigned(n), Ty::F64) => Value::Float(n as f64),
+        (Value::Float(f), Ty::F64) => Value::Float(f),
+        (Value::Signed(n), _) => truncate(ty, n as u128),
+        (Value::Unsigned(n), _) => truncate(ty, n),
+        // Casting a float to an integer saturates, and `NaN` becomes `0`.
+        (Value::Float(f), _) if ty.is_signed() => {
+            let (min, max) = ty.signed_range();
+
+            Value::Signed((f as i128).clamp(min, max))
+        },
+        (Value::Float(f), _) => Value::Unsigned((f as u128).min(ty.unsigned_max())),
+    }
+}
+
+/// Gets the type and the value of a well-known constant, like `u32::MAX` or `std::f64::consts::PI`.
+fn constant(segments: &[&str]) -> Option<(Ty, Value)> {
+    let segments = match segments {
+        ["std" | "core", segments @ ..] => segments,
+        _ => segments,
+    };
+
+    let (ty, name) = match segments {
+        [ty, name] => (Ty::from_name(ty)?, *name),
+        [ty @ ("f32" | "f64"), "consts", name] => return float_constant(Ty::from_name(ty)?, name),
+        _ => return None,
+    };
+
+    if ty.is_float() {
+        return match name {
+            "MAX" | "MIN" | "EPSILON" | "MIN_POSITIVE" | "NAN" | "INFINITY" | "NEG_INFINITY" => {
+                float_constant(ty, name)
+            },
+            _ => None,
+        };
+    }
+
+    let value = match name {
+        "MAX" if ty.is_signed() => Value::Signed(ty.signed_range().1),
+        "MAX" => Value::Unsigned(ty.unsigned_max()),
+        "MIN" if ty.is_signed() => Value::Signed(ty.signed_range().0),
+        "MIN" => Value::Unsigned(0),
+        "BITS" => return Some((Ty::U32, Value::Unsigned(ty.bits() as u128))),
+        _ => return None,
+    };
+
+    Some((ty, value))
+}
+
+macro_rules! float_constants {
+    ($ty:expr, $name:expr, [$($c:ident),* $(,)*], [$($m:ident),* $(,)*]) => {
+        match ($ty, $name) {
+            $(
+                (Ty::F32, stringify!($c)) => f32::$c as f64,
+                (Ty::F64, stringify!($c)) => f64::$c,
+            )*
+            $(
+                (Ty::F32, stringify!($m)) => std::f32::consts::$m as f64,
+                (Ty::F64, stringify!($m)) => std::f64::consts::$m,
+            )*
+            _ => return None,
+        }
+    };
+}
+
+fn float_constant(ty: Ty, name: &str) -> Option<(Ty, Value)> {
+    let f = float_constants!(
+        ty,
+        name,
+        [MAX, MIN, EPSILON, MIN_POSITIVE, NAN, INFINITY, NEG_INFINITY],
+        [
+            E,
+            FRAC_1_PI,
+            FRAC_1_SQRT_2,
+            FRAC_2_PI,
+            FRAC_2_SQRT_PI,
+            FRAC_PI_2,
+            FRAC_PI_3,
+            FRAC_PI_4,
+            FRAC_PI_6,
+            FRAC_PI_8,
+            LN_2,
+            LN_10,
+            LOG2_10,
+            LOG2_E,
+            LOG10_2,
+            LOG10_E,
+            PI,
+            SQRT_2,
+            TAU,
+        ]
+    );
+
+    Some((ty, Value::Float(f)))
+}
+
+/// Infers the type of an expression from its suffixes, casts and constants, like what rust does without the type context.
+pub fn infer(expr: &Expr) -> Option<Ty> {
+    match &expr.kind {
+        ExprKind::Lit(Lit::Int {
+            suffix, ..
+        })
+        | ExprKind::Lit(Lit::Float {
+            suffix, ..
+        }) => Ty::from_name(suffix),
+        ExprKind::Lit(Lit::Byte(_)) => Some(Ty::U8),
+        ExprKind::Neg(expr) | ExprKind::Not(expr) | ExprKind::Paren(expr) => infer(expr),
+        // The type of a shift is the type of its left-hand side.
+        ExprKind::Binary(BinOp::Shl | BinOp::Shr, lhs, _) => infer(lhs),
+        ExprKind::Binary(_, lhs, rhs) => infer(lhs).or_else(|| infer(rhs)),
+        ExprKind::Cast(_, ty) => Ty::from_name(ty),
+        ExprKind::Path(segments) => constant(segments).map(|(ty, _)| ty),
+        _ => None,
+    }
+}
+
+/// Checks whether an expression contains float literals which decide its type.
+fn is_float(expr: &Expr) -> bool {
+    match &expr.kind {
+        ExprKind::Lit(Lit::Float {
+            ..
+        }) => true,
+        ExprKind::Neg(expr) | ExprKind::Not(expr) | ExprKind::Paren(expr) => is_float(expr),
+        ExprKind::Binary(BinOp::Shl | BinOp::Shr, lhs, _) => is_float(lhs),
+        ExprKind::Binary(_, lhs, rhs) => is_float(lhs) || is_float(rhs),
+        _ => false,
+    }
+}
+
+/// Infers the type of an expression. Integer expressions are `i32` and float expressions are `f64` by default.
+#[inline]
+fn infer_or_default(expr: &Expr) -> Ty {
+    infer(expr).unwrap_or(if is_float(expr) { Ty::F64 } else { Ty::I32 })
+}
+
+/// Infers the type of the operand of a cast to the type `ty`. Like what rust does, an unsuffixed literal, which can be negated, inverted or parenthesized, has the type of the cast if the type is of the same kind.
+fn infer_cast_operand(expr: &Expr, ty: Ty) -> Ty {
+    fn hint(expr: &Expr, ty: Ty) -> Option<Ty> {
+        match &expr.kind {
+            ExprKind::Lit(Lit::Int {
+                ..
+            }) if !ty.is_float() => Some(ty),
+            ExprKind::Lit(Lit::Float {
+                ..
+            }) if ty.is_float() => Some(ty),
+            ExprKind::Neg(expr) | ExprKind::Not(expr) | ExprKind::Paren(expr) => hint(expr, ty),
+            _ => None,
+        }
+    }
+
+    infer(expr).or_else(|| hint(expr, ty)).unwrap_or_else(|| infer_or_default(expr))
+}
+
+/// Parses the digits of a float literal as a value of the float type `ty`.
+fn parse_float(digits: &str, ty: Ty) -> Option<Value> {
+    // Parse `f32` literals directly to avoid rounding twice.
+    let f = if ty == Ty::F32 {
+        digits.parse::<f32>().map(f64::from).ok()?
+    } else {
+        digits.parse::<f64>().ok()?
+    };
+
+    Some(Value::Float(f))
+}
+
+/// Evaluates a constant expression as a value of the type `ty`.
+pub fn eval<'e>(expr: &'e Expr<'e>, ty: Ty) -> Result<Value, EvalError<'e>> {
+    let wrong_type = || EvalError::WrongType {
+        expr,
+        expected: ty,
+    };
+    let overflow = || EvalError::Overflow {
+        expr,
+        ty,
+    };
+
+    match &expr.kind {
+        ExprKind::Lit(Lit::Int {
+            digits,
+            suffix,
+        }) => {
+            if !suffix.is_empty() && Ty::from_name(suffix) != Some(ty) {
+                return Err(wrong_type());
+            }
+
+            // Integer literals are also floats in float contexts, as the embed mode makes them.
+            if ty.is_float() {
+                return parse_float(digits, ty).ok_or_else(wrong_type);
+            }
+
+            let n: u128 = digits.parse().map_err(|_| overflow())?;
+
+            fit(ty, Value::Unsigned(n)).ok_or_else(overflow)
+        },
+        ExprKind::Lit(Lit::Float {
+            digits,
+            suffix,
+        }) => {
+            if !ty.is_float() || !suffix.is_empty() && Ty::from_name(suffix) != Some(ty) {
+                return Err(wrong_type());
+            }
+
+            parse_float(digits, ty).ok_or_else(wrong_type)
+        },
+        ExprKind::Lit(Lit::Byte(b)) if ty == Ty::U8 => Ok(Value::Unsigned(*b as u128)),
+        ExprKind::Paren(inner) => eval(inner, ty),
+        ExprKind::Neg(inner) => {
+            // A negative value is out of the range of unsigned types.
+            if !ty.is_signed() && !ty.is_float() {
+                return Err(overflow());
+            }
+
+            match &inner.kind {
+                // Negate integer literals before checking their ranges, so that minimum values like `-128i8` are in the ranges.
+                ExprKind::Lit(Lit::Int {
+                    digits,
+                    suffix,
+                }) if ty.is_signed() => {
+                    if !suffix.is_empty() && Ty::from_name(suffix) != Some(ty) {
+                        return Err(EvalError::WrongType {
+                            expr: inner, expected: ty
+                        });
+                    }
+
+                    let n: u128 = digits.parse().map_err(|_| overflow())?;
+
+                    0i128
+                        .checked_sub_unsigned(n)
+                        .and_then(|n| fit(ty, Value::Signed(n)))
+                        .ok_or_else(overflow)
+                },
+                _ => match eval(inner, ty)? {
+                    Value::Signed(n) => {
+                        n.checked_neg().and_then(|n| fit(ty, Value::Signed(n))).ok_or_else(overflow)
+                    },
+                    Value::Float(f) => Ok(Value::Float(-f)),
+                    Value::Unsigned(_) => Err(wrong_type()),
+                },
+            }
+        },
+        ExprKind::Not(inner) => match eval(inner, ty)? {
+            Value::Signed(n) => Ok(Value::Signed(!n)),
+            Value::Unsigned(n) => Ok(Value::Unsigned(!n & ty.unsigned_max())),
+            Value::Float(_) => Err(wrong_type()),
+        },
+        ExprKind::Binary(op @ (BinOp::Shl | BinOp::Shr), lhs, rhs) => {
+            let value = eval(lhs, ty)?;
+
+            let rhs_ty = infer_or_default(rhs);
+
+            if rhs_ty.is_float() {
+                return Err(EvalError::WrongType {
+                    expr: rhs, expected: Ty::U32
+                });
+            }
+
+            // Shifting by the width of the type or more overflows.
+            let amount = match eval(rhs, rhs_ty)? {
+                Value::Signed(n) => u32::try_from(n).ok(),
+                Value::Unsigned(n) => u32::try_from(n).ok(),
+                Value::Float(_) => None,
+            }
+            .filter(|&amount| amount < ty.bits())
+            .ok_or_else(overflow)?;
+
+            match (op, value) {
+                (BinOp::Shl, Value::Signed(n)) => Ok(truncate(ty, (n as u128) << amount)),
+                (BinOp::Shl, Value::Unsigned(n)) => Ok(truncate(ty, n << amount)),
+                (_, Value::Signed(n)) => Ok(Value::Signed(n >> amount)),
+                (_, Value::Unsigned(n)) => Ok(Value::Unsigned(n >> amount)),
+                (_, Value::Float(_)) => Err(wrong_type()),
+            }
+        },
+        ExprKind::Binary(op, lhs, rhs) => match (eval(lhs, ty)?, eval(rhs, ty)?) {
+            (Value::Signed(a), Value::Signed(b)) => {
+                let n = match op {
+                    BinOp::Add => a.checked_add(b),
+                    BinOp::Sub => a.checked_sub(b),
+                    BinOp::Mul => a.checked_mul(b),
+                    BinOp::Div => a.checked_div(b),
+                    BinOp::Rem => a.checked_rem(b),
+                    BinOp::BitAnd => Some(a & b),
+                    BinOp::BitOr => Some(a | b),
+                    BinOp::BitXor => Some(a ^ b),
+                    BinOp::Shl | BinOp::Shr => unreachable!(),
+                };
+
+                n.and_then(|n| fit(ty, Value::Signed(n))).ok_or_else(overflow)
+            },
+            (Value::Unsigned(a), Value::Unsigned(b)) => {
+                let n = match op {
+                    BinOp::Add => a.checked_add(b),
+                    BinOp::Sub => a.checked_sub(b),
+                    BinOp::Mul => a.checked_mul(b),
+                    BinOp::Div => a.checked_div(b),
+                    BinOp::Rem => a.checked_rem(b),
+                    BinOp::BitAnd => Some(a & b),
+                    BinOp::BitOr => Some(a | b),
+                    BinOp::BitXor => Some(a ^ b),
+                    BinOp::Shl | BinOp::Shr => unreachable!(),
+                };
+
+                n.and_then(|n| fit(ty, Value::Unsigned(n))).ok_or_else(overflow)
+            },
+            (Value::Float(a), Value::Float(b)) => {
+                let f = match op {
+                    BinOp::Add => a + b,
+                    BinOp::Sub => a - b,
+                    BinOp::Mul => a * b,
+                    BinOp::Div => a / b,
+                    BinOp::Rem => a % b,
+                    _ => return Err(wrong_type()),
+                };
+
+                // Operations on `f32` values computed as `f64` ones and then rounded are exact.
+                Ok(cast(Value::Float(f), ty))
+            },
+            _ => Err(wrong_type()),
+        },
+        ExprKind::Cast(inner, name) => {
+            if Ty::from_name(name) != Some(ty) {
+                return Err(wrong_type());
+            }
+
+            let value = match &inner.kind {
+                // `bool` and `char` values can only be cast to integers.
+                ExprKind::Lit(Lit::Bool(b)) if !ty.is_float() => Value::Unsigned(*b as u128),
+                ExprKind::Lit(Lit::Char(c)) if !ty.is_float() => Value::Unsigned(*c as u128),
+                _ => eval(inner, infer_cast_operand(inner, ty))?,
+            };
+
+            Ok(cast(value, ty))
+        },
+        ExprKind::Path(segments) => match constant(segments) {
+            Some((const_ty, value)) if const_ty == ty => Ok(value),
+            _ => Err(wrong_type()),
+        },
+        _ => Err(wrong_type()),
+    }
+}
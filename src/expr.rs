@@ -0,0 +1,825 @@
+//! Expressions in rust array files, which are parsed when the files are loaded at runtime.
+
+use crate::ArrayIncludeError;
+
+/// A literal in a rust array file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Lit {
+    /// An integer literal. `digits` are the decimal digits of the value (or the original digits if the value is too large), and `suffix` is the type suffix which can be empty.
+    Int {
+        digits: String,
+        suffix: String,
+    },
+    /// A floating-point literal. `digits` are the digits without underscores, and `suffix` is the type suffix which can be empty.
+    Float {
+        digits: String,
+        suffix: String,
+    },
+    Bool(bool),
+    Char(char),
+    Byte(u8),
+    Str(String),
+    ByteStr(Vec<u8>),
+}
+
+/// Binary operators in a rust array file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Shl,
+    Shr,
+    BitAnd,
+    BitOr,
+    BitXor,
+}
+
+impl BinOp {
+    /// Binary operators grouped by their precedence, from the lowest one to the highest one.
+    const PRECEDENCE: [&'static [(&'static str, BinOp)]; 6] = [
+        &[("|", BinOp::BitOr)],
+        &[("^", BinOp::BitXor)],
+        &[("&", BinOp::BitAnd)],
+        &[("<<", BinOp::Shl), (">>", BinOp::Shr)],
+        &[("+", BinOp::Add), ("-", BinOp::Sub)],
+        &[("*", BinOp::Mul), ("/", BinOp::Div), ("%", BinOp::Rem)],
+    ];
+}
+
+/// The kinds of expressions in a rust array file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExprKind<'a> {
+    Lit(Lit),
+    /// `-expr`
+    Neg(Box<Expr<'a>>),
+    /// `!expr`
+    Not(Box<Expr<'a>>),
+    /// `&expr`
+    Reference(Box<Expr<'a>>),
+    /// `expr op expr`
+    Binary(BinOp, Box<Expr<'a>>, Box<Expr<'a>>),
+    /// `expr as type`
+    Cast(Box<Expr<'a>>, &'a str),
+    /// `[expr, ...]`
+    Array(Vec<Expr<'a>>),
+    /// `[expr; length]`
+    Repeat(Box<Expr<'a>>, Box<Expr<'a>>),
+    /// `(expr, ...)`
+    Tuple(Vec<Expr<'a>>),
+    /// `(expr)`
+    Paren(Box<Expr<'a>>),
+    /// `a::b::c`
+    Path(Vec<&'a str>),
+    /// `a::b::c(expr, ...)`
+    Call(Vec<&'a str>, Vec<Expr<'a>>),
+}
+
+/// An expression in a rust array file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expr<'a> {
+    pub kind:   ExprKind<'a>,
+    /// The source text of the expression.
+    pub text:   &'a str,
+    /// The 1-based line number where the expression starts.
+    pub line:   usize,
+    /// The 1-based column number (in characters) where the expression starts.
+    pub column: usize,
+}
+
+impl<'a> Expr<'a> {
+    /// Gets the segments if the expression is a path, like `["Color", "Red"]` of `Color::Red`.
+    #[inline]
+    pub fn path(&self) -> Option<&[&'a str]> {
+        match &self.kind {
+            ExprKind::Path(segments) => Some(segments),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Position {
+    offset: usize,
+    line:   usize,
+    column: usize,
+}
+
+struct Parser<'a> {
+    text:     &'a str,
+    position: Position,
+}
+
+impl<'a> Parser<'a> {
+    #[inline]
+    fn rest(&self) -> &'a str {
+        &self.text[self.position.offset..]
+    }
+
+    #[inline]
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    #[inline]
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.rest().chars().nth(n)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+
+        self.position.offset += c.len_utf8();
+
+        if c == '\n' {
+            self.position.line += 1;
+            self.position.column = 1;
+        } else {
+            self.position.column += 1;
+        }
+
+        Some(c)
+    }
+
+    #[inline]
+    fn error_at<T>(
+        &self,
+        position: Position,
+        message: impl Into<String>,
+    ) -> Result<T, ArrayIncludeError> {
+        Err(ArrayIncludeError::Syntax {
+            line:    position.line,
+            column:  position.column,
+            message: message.into(),
+        })
+    }
+
+    #[inline]
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, ArrayIncludeError> {
+        self.error_at(self.position, message)
+    }
+
+    /// Skips whitespaces and comments.
+    fn skip_trivia(&mut self) -> Result<(), ArrayIncludeError> {
+        loop {
+            let rest = self.rest();
+
+            if rest.starts_with("//") {
+                while !matches!(self.peek(), None | Some('\n')) {
+                    self.bump();
+                }
+            } else if rest.starts_with("/*") {
+                let start = self.position;
+                let mut depth = 0usize;
+
+                loop {
+                    let rest = self.rest();
+
+                    if rest.starts_with("/*") {
+                        depth += 1;
+                        self.bump();
+                        self.bump();
+                    } else if rest.starts_with("*/") {
+                        depth -= 1;
+                        self.bump();
+                        self.bump();
+
+                        if depth == 0 {
+                            break;
+                        }
+                    } else if self.bump().is_none() {
+                        return self.error_at(start, "unterminated block comment");
+                    }
+                }
+            } else if self.peek().is_some_and(char::is_whitespace) {
+                self.bump();
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn expr_from(&self, start: Position, kind: ExprKind<'a>) -> Expr<'a> {
+        Expr {
+            kind,
+            text: &self.text[start.offset..self.position.offset],
+            line: start.line,
+            column: start.column,
+        }
+    }
+
+    #[inline]
+    fn parse_expr(&mut self) -> Result<Expr<'a>, ArrayIncludeError> {
+        self.parse_binary(0)
+    }
+
+    /// Parses binary operations whose precedence is not lower than the `level`-th group of `BinOp::PRECEDENCE`.
+    fn parse_binary(&mut self, level: usize) -> Result<Expr<'a>, ArrayIncludeError> {
+        if level == BinOp::PRECEDENCE.len() {
+            return self.parse_cast();
+        }
+
+        self.skip_trivia()?;
+
+        let start = self.position;
+
+        let mut lhs = self.parse_binary(level + 1)?;
+
+        loop {
+            let end = self.position;
+
+            self.skip_trivia()?;
+
+            let rest = self.rest();
+
+            // `&&`, `||`, `+=`, `<<=` and so on are not the operators here.
+            let op = BinOp::PRECEDENCE[level].iter().find(|(token, _)| {
+                rest.strip_prefix(token).is_some_and(|after| {
+                    !(after.starts_with('=')
+                        || matches!(*token, "&" | "|") && after.starts_with(token))
+                })
+            });
+
+            let Some((token, op)) = op else {
+                // Keep the trailing trivia for the following tokens.
+                self.position = end;
+
+                return Ok(lhs);
+            };
+
+            for _ in 0..token.len() {
+                self.bump();
+            }
+
+            let rhs = self.parse_binary(level + 1)?;
+
+            lhs = self.expr_from(start, ExprKind::Binary(*op, Box::new(lhs), Box::new(rhs)));
+        }
+    }
+
+    fn parse_cast(&mut self) -> Result<Expr<'a>, ArrayIncludeError> {
+        self.skip_trivia()?;
+
+        let start = self.position;
+
+        let mut expr = self.parse_unary()?;
+
+        loop {
+            let end = self.position;
+
+            self.skip_trivia()?;
+
+            let is_cast = self
+                .rest()
+                .strip_prefix("as")
+                .is_some_and(|after| !after.starts_with(|c: char| c.is_alphanumeric() || c == '_'));
+
+            if !is_cast {
+                // Keep the trailing trivia for the following tokens.
+                self.position = end;
+
+                return Ok(expr);
+            }
+
+            self.bump();
+            self.bump();
+            self.skip_trivia()?;
+
+            match self.peek() {
+                Some(c) if c.is_alphabetic() || c == '_' => {
+                    let ty = self.parse_ident();
+
+                    expr = self.expr_from(start, ExprKind::Cast(Box::new(expr), ty));
+                },
+                _ => return self.error("expected a type"),
+            }
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr<'a>, ArrayIncludeError> {
+        self.skip_trivia()?;
+
+        let start = self.position;
+
+        let wrap: fn(Box<Expr<'a>>) -> ExprKind<'a> = match self.peek() {
+            Some('-') => ExprKind::Neg,
+            Some('!') => ExprKind::Not,
+            Some('&') => ExprKind::Reference,
+            _ => return self.parse_primary(),
+        };
+
+        self.bump();
+
+        let expr = self.parse_unary()?;
+
+        Ok(self.expr_from(start, wrap(Box::new(expr))))
+    }
+
+    /// Parses comma-separated expressions until `close`. Returns the expressions and whether there is a trailing comma.
+    fn parse_list(&mut self, close: char) -> Result<(Vec<Expr<'a>>, bool), ArrayIncludeError> {
+        let mut exprs = Vec::new();
+
+        loop {
+            self.skip_trivia()?;
+
+            if self.peek() == Some(close) {
+                self.bump();
+
+                return Ok((exprs, true));
+            }
+
+            exprs.push(self.parse_expr()?);
+
+            self.skip_trivia()?;
+
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                },
+                Some(c) if c == close => {
+                    self.bump();
+
+                    return Ok((exprs, false));
+                },
+                _ => return self.error(format!("expected `,` or `{close}`")),
+            }
+        }
+    }
+
+    /// Parses an array after `[`, which can be comma-separated expressions or an array repeat expression.
+    fn parse_array(&mut self) -> Result<ExprKind<'a>, ArrayIncludeError> {
+        self.skip_trivia()?;
+
+        if self.peek() == Some(']') {
+            self.bump();
+
+            return Ok(ExprKind::Array(Vec::new()));
+        }
+
+        let expr = self.parse_expr()?;
+
+        self.skip_trivia()?;
+
+        match self.peek() {
+            Some(',') => {
+                self.bump();
+
+                let mut exprs = self.parse_list(']')?.0;
+
+                exprs.insert(0, expr);
+
+                Ok(ExprKind::Array(exprs))
+            },
+            Some(']') => {
+                self.bump();
+
+                Ok(ExprKind::Array(vec![expr]))
+            },
+            Some(';') => {
+                self.bump();
+
+                let length = self.parse_expr()?;
+
+                self.skip_trivia()?;
+
+                if self.peek() != Some(']') {
+                    return self.error("expected `]`");
+                }
+
+                self.bump();
+
+                Ok(ExprKind::Repeat(Box::new(expr), Box::new(length)))
+            },
+            _ => self.error("expected `,`, `;` or `]`"),
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr<'a>, ArrayIncludeError> {
+        let start = self.position;
+
+        let kind = match self.peek() {
+            Some('[') => {
+                self.bump();
+
+                self.parse_array()?
+            },
+            Some('(') => {
+                self.bump();
+
+                let (mut exprs, trailing_comma) = self.parse_list(')')?;
+
+                if exprs.len() == 1 && !trailing_comma {
+                    ExprKind::Paren(Box::new(exprs.remove(0)))
+                } else {
+                    ExprKind::Tuple(exprs)
+                }
+            },
+            Some('0'..='9') => ExprKind::Lit(self.parse_number()?),
+            Some('\'') => {
+                self.bump();
+
+                ExprKind::Lit(Lit::Char(self.parse_char(false)?))
+            },
+            Some('"') => {
+                self.bump();
+
+                let data = self.parse_quoted(start, false)?;
+
+                // Only whole characters are pushed into the data.
+                ExprKind::Lit(Lit::Str(String::from_utf8(data).unwrap()))
+            },
+            Some('b') if self.peek_nth(1) == Some('\'') => {
+                self.bump();
+                self.bump();
+
+                // All characters of byte literals are in the range of `u8` here.
+                ExprKind::Lit(Lit::Byte(self.parse_char(true)? as u8))
+            },
+            Some('b') if self.peek_nth(1) == Some('"') => {
+                self.bump();
+                self.bump();
+
+                ExprKind::Lit(Lit::ByteStr(self.parse_quoted(start, true)?))
+            },
+            Some('b')
+                if self.peek_nth(1) == Some('r') && matches!(self.peek_nth(2), Some('"' | '#')) =>
+            {
+                self.bump();
+                self.bump();
+
+                let s = self.parse_raw_str(start)?;
+
+                if !s.is_ascii() {
+                    return self.error_at(start, "non-ASCII character in byte string literal");
+                }
+
+                ExprKind::Lit(Lit::ByteStr(s.into_bytes()))
+            },
+            Some('r') if matches!(self.peek_nth(1), Some('"' | '#')) => {
+                self.bump();
+
+                ExprKind::Lit(Lit::Str(self.parse_raw_str(start)?))
+            },
+            Some(c) if c.is_alphabetic() || c == '_' => {
+                let mut segments = vec![self.parse_ident()];
+
+                while self.rest().starts_with("::") {
+                    self.bump();
+                    self.bump();
+
+                    match self.peek() {
+                        Some(c) if c.is_alphabetic() || c == '_' => {
+                            segments.push(self.parse_ident())
+                        },
+                        _ => return self.error("expected an identifier"),
+                    }
+                }
+
+                let end = self.position;
+
+                self.skip_trivia()?;
+
+                if self.peek() == Some('(') {
+                    self.bump();
+
+                    ExprKind::Call(segments, self.parse_list(')')?.0)
+                } else {
+                    // Keep the trailing trivia for the following tokens.
+                    self.position = end;
+
+                    match segments.as_slice() {
+                        ["true"] => ExprKind::Lit(Lit::Bool(true)),
+                        ["false"] => ExprKind::Lit(Lit::Bool(false)),
+                        _ => ExprKind::Path(segments),
+                    }
+                }
+            },
+            _ => return self.error("expected an expression"),
+        };
+
+        Ok(self.expr_from(start, kind))
+    }
+
+    fn parse_ident(&mut self) -> &'a str {
+        let start = self.position.offset;
+
+        while self.peek().is_some_and(|c| c.is_alphanumeric() || c == '_') {
+            self.bump();
+        }
+
+        &self.text[start..self.position.offset]
+    }
+
+    fn parse_digits(&mut self, radix: u32) -> String {
+        let mut digits = String::new();
+
+        while let Some(c) = self.peek() {
+            if c == '_' {
+                self.bump();
+            } else if c.is_digit(radix) {
+                digits.push(c);
+                self.bump();
+            } else {
+                break;
+            }
+        }
+
+        digits
+    }
+
+    fn parse_number(&mut self) -> Result<Lit, ArrayIncludeError> {
+        let start = self.position;
+
+        let radix = match (self.peek(), self.peek_nth(1)) {
+            (Some('0'), Some('x')) => 16,
+            (Some('0'), Some('o')) => 8,
+            (Some('0'), Some('b')) => 2,
+            _ => 10,
+        };
+
+        if radix != 10 {
+            self.bump();
+            self.bump();
+
+            let digits = self.parse_digits(radix);
+
+            if digits.is_empty() {
+                return self.error_at(start, "missing digits after the integer base prefix");
+            }
+
+            let suffix = self.parse_ident().to_string();
+
+            // Convert the digits into decimal ones, so that the value can be parsed by `str::parse`. A value which is too large is kept as it is and overflows anyway.
+            let digits = match u128::from_str_radix(&digits, radix) {
+                Ok(n) => n.to_string(),
+                Err(_) => self.text[start.offset..self.position.offset - suffix.len()].to_string(),
+            };
+
+            return Ok(Lit::Int {
+                digits,
+                suffix,
+            });
+        }
+
+        let mut digits = self.parse_digits(10);
+        let mut is_float = false;
+
+        // A dot followed by another dot or an identifier is not a part of the number, like what rust does.
+        if self.peek() == Some('.')
+            && !self.peek_nth(1).is_some_and(|c| c == '.' || c == '_' || c.is_alphabetic())
+        {
+            self.bump();
+
+            is_float = true;
+            digits.push('.');
+            digits.push_str(&self.parse_digits(10));
+        }
+
+        if matches!(self.peek(), Some('e' | 'E')) {
+            let sign = self.peek_nth(1).filter(|c| matches!(c, '+' | '-'));
+            let first_digit = self.peek_nth(if sign.is_some() { 2 } else { 1 });
+
+            if first_digit.is_some_and(|c| c.is_ascii_digit() || c == '_') {
+                self.bump();
+
+                is_float = true;
+                digits.push('e');
+
+                if let Some(sign) = sign {
+                    self.bump();
+                    digits.push(sign);
+                }
+
+                digits.push_str(&self.parse_digits(10));
+            }
+        }
+
+        let suffix = self.parse_ident().to_string();
+
+        if is_float {
+            Ok(Lit::Float {
+                digits,
+                suffix,
+            })
+        } else {
+            Ok(Lit::Int {
+                digits,
+                suffix,
+            })
+        }
+    }
+
+    /// Parses an escape sequence after the backslash.
+    fn parse_escape(&mut self, unicode: bool) -> Result<char, ArrayIncludeError> {
+        let start = self.position;
+
+        match self.bump() {
+            Some('n') => Ok('\n'),
+            Some('r') => Ok('\r'),
+            Some('t') => Ok('\t'),
+            Some('\\') => Ok('\\'),
+            Some('0') => Ok('\0'),
+            Some('\'') => Ok('\''),
+            Some('"') => Ok('"'),
+            Some('x') => {
+                let hex = self.rest().get(..2).unwrap_or_default();
+
+                match u8::from_str_radix(hex, 16) {
+                    Ok(b) if !unicode || b.is_ascii() => {
+                        self.bump();
+                        self.bump();
+
+                        Ok(char::from(b))
+                    },
+                    _ => self.error_at(start, "incorrect `\\x` escape"),
+                }
+            },
+            Some('u') if unicode => {
+                let rest = self.rest();
+
+                let hex = rest
+                    .strip_prefix('{')
+                    .and_then(|rest| rest.split_once('}'))
+                    .map(|(hex, _)| hex);
+
+                match hex
+                    .and_then(|hex| u32::from_str_radix(&hex.replace('_', ""), 16).ok())
+                    .and_then(char::from_u32)
+                {
+                    Some(c) => {
+                        // `{`, the digits and `}`
+                        for _ in 0..hex.unwrap().chars().count() + 2 {
+                            self.bump();
+                        }
+
+                        Ok(c)
+                    },
+                    None => self.error_at(start, "incorrect `\\u` escape"),
+                }
+            },
+            _ => self.error_at(start, "unknown character escape"),
+        }
+    }
+
+    /// Parses a character literal after the opening quote. Characters of byte literals need to be ASCII, but their `\x` escapes can be any bytes.
+    fn parse_char(&mut self, byte: bool) -> Result<char, ArrayIncludeError> {
+        let start = self.position;
+
+        let c = match self.bump() {
+            Some('\\') => self.parse_escape(!byte)?,
+            Some('\'') | None => return self.error_at(start, "empty character literal"),
+            Some(c) if byte && !c.is_ascii() => {
+                return self.error_at(start, "non-ASCII character in byte literal");
+            },
+            Some(c) => c,
+        };
+
+        if self.bump() != Some('\'') {
+            return self.error_at(start, "expected `'`");
+        }
+
+        Ok(c)
+    }
+
+    /// Parses a string literal after the opening quote. Characters of byte strings need to be ASCII, but their `\x` escapes can be any bytes.
+    fn parse_quoted(&mut self, start: Position, byte: bool) -> Result<Vec<u8>, ArrayIncludeError> {
+        let mut data = Vec::new();
+
+        loop {
+            let c = match self.bump() {
+                Some('"') => return Ok(data),
+                Some('\\') => {
+                    // A backslash at the end of a line skips the line break and the leading whitespaces of the next line.
+                    if matches!(self.peek(), Some('\n' | '\r')) {
+                        while self.peek().is_some_and(char::is_whitespace) {
+                            self.bump();
+                        }
+
+                        continue;
+                    }
+
+                    self.parse_escape(!byte)?
+                },
+                Some(c) if byte && !c.is_ascii() => {
+                    return self.error("non-ASCII character in byte string literal");
+                },
+                Some(c) => c,
+                None => return self.error_at(start, "unterminated string literal"),
+            };
+
+            if byte {
+                // All characters of byte strings are in the range of `u8` here.
+                data.push(c as u8);
+            } else {
+                data.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            }
+        }
+    }
+
+    /// Parses a raw string literal after `r` or `br`.
+    fn parse_raw_str(&mut self, start: Position) -> Result<String, ArrayIncludeError> {
+        let mut hashes = 0;
+
+        while self.peek() == Some('#') {
+            self.bump();
+            hashes += 1;
+        }
+
+        if self.bump() != Some('"') {
+            return self.error_at(start, "expected `\"`");
+        }
+
+        let terminator = format!("\"{}", "#".repeat(hashes));
+
+        match self.rest().find(&terminator) {
+            Some(length) => {
+                let s = self.rest()[..length].to_string();
+
+                for _ in 0..s.chars().count() + terminator.len() {
+                    self.bump();
+                }
+
+                Ok(s)
+            },
+            None => self.error_at(start, "unterminated raw string literal"),
+        }
+    }
+}
+
+/// Parses a text containing a single expression.
+pub fn parse(text: &str) -> Result<Expr<'_>, ArrayIncludeError> {
+    let mut parser = Parser {
+        text,
+        position: Position {
+            offset: 0, line: 1, column: 1
+        },
+    };
+
+    let expr = parser.parse_expr()?;
+
+    parser.skip_trivia()?;
+
+    if parser.peek().is_some() {
+        return parser.error("unexpected token");
+    }
+
+    Ok(expr)
+}
+
+/// Checks whether a text starts with an array after skipping whitespaces and comments.
+pub fn starts_with_array(text: &str) -> Result<bool, ArrayIncludeError> {
+    let mut parser = Parser {
+        text,
+        position: Position {
+            offset: 0, line: 1, column: 1
+        },
+    };
+
+    parser.skip_trivia()?;
+
+    Ok(parser.peek() == Some('['))
+}
+
+/// Parses a `key = value` line whose 1-based line number is `line`. Returns `None` if the line is empty or only has comments.
+pub fn parse_entry(
+    text: &str,
+    line: usize,
+) -> Result<Option<(Expr<'_>, Expr<'_>)>, ArrayIncludeError> {
+    let mut parser = Parser {
+        text,
+        position: Position {
+            offset: 0,
+            line,
+            column: 1,
+        },
+    };
+
+    parser.skip_trivia()?;
+
+    if parser.peek().is_none() {
+        return Ok(None);
+    }
+
+    let key = parser.parse_expr()?;
+
+    parser.skip_trivia()?;
+
+    if parser.bump() != Some('=') {
+        return parser.error("expected `=`");
+    }
+
+    let value = parser.parse_expr()?;
+
+    parser.skip_trivia()?;
+
+    if parser.peek().is_some() {
+        return parser.error("unexpected token");
+    }
+
+    Ok(Some((key, value)))
+}
@@ -0,0 +1,136 @@
+use std::{fs, sync::Mutex, time::SystemTime};
+
+use crate::root;
+
+/// A handle to the content of a file which is reloaded whenever the file is modified.
+///
+/// Instances are created by the `hot` items of the `lazy_static_include_bytes` and `lazy_static_include_str` macros. When the file is embedded into the executable binary file, `get` always returns the embedded data.
+pub struct HotReload<T: ?Sized + 'static> {
+    source: Source<T>,
+}
+
+enum Source<T: ?Sized + 'static> {
+    Embedded(&'static T),
+    File {
+        manifest_dir: Option<&'static str>,
+        path:         &'static str,
+        load:         fn(Vec<u8>) -> Result<&'static T, String>,
+        cache:        Mutex<Option<Cache<T>>>,
+    },
+}
+
+struct Cache<T: ?Sized + 'static> {
+    path:     &'static str,
+    modified: Option<SystemTime>,
+    data:     &'static T,
+}
+
+impl<T: ?Sized + 'static> HotReload<T> {
+    /// Creates a handle to embedded data, which never changes.
+    #[inline]
+    pub const fn embedded(data: &'static T) -> Self {
+        HotReload {
+            source: Source::Embedded(data)
+        }
+    }
+
+    #[inline]
+    const fn file(
+        manifest_dir: Option<&'static str>,
+        path: &'static str,
+        load: fn(Vec<u8>) -> Result<&'static T, String>,
+    ) -> Self {
+        HotReload {
+            source: Source::File {
+                manifest_dir,
+                path,
+                load,
+                cache: Mutex::new(None),
+            },
+        }
+    }
+
+    /// Gets the latest content of the file.
+    ///
+    /// The file is read again if its modification time has changed since the last read. Every version of the content is leaked to get a `&'static` reference, so this is only intended to be used during development.
+    pub fn get(&self) -> &'static T {
+        match &self.source {
+            Source::Embedded(data) => data,
+            Source::File {
+                manifest_dir,
+                path,
+                load,
+                cache,
+            } => {
+                let mut cache = cache.lock().unwrap_or_else(|err| err.into_inner());
+
+                // The path is rebased onto the runtime root only once.
+                let path = match cache.as_ref() {
+                    Some(cache) => cache.path,
+                    None => match manifest_dir {
+                        Some(manifest_dir) => root::rebase(manifest_dir, path),
+                        None => path,
+                    },
+                };
+
+                let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+
+                if let Some(cache) = cache.as_ref() {
+                    // Keep the last content if the file is temporarily unavailable (e.g. being replaced by an editor).
+                    if modified.is_none() || cache.modified == modified {
+                        return cache.data;
+                    }
+                }
+
+                let data = match fs::read(path).map_err(|err| err.to_string()).and_then(load) {
+                    Ok(data) => data,
+                    Err(err) => match cache.as_ref() {
+                        // Keep the last content if the file cannot be read or is invalid (e.g. half-written by an editor). It is read again on the next call because its modification time is not cached.
+                        Some(cache) => return cache.data,
+                        None => panic!("{err}, file: {path}"),
+                    },
+                };
+
+                *cache = Some(Cache {
+                    path,
+                    modified,
+                    data,
+                });
+
+                data
+            },
+        }
+    }
+}
+
+impl HotReload<[u8]> {
+    /// Creates a handle to a file which is read as a byte array (`&'static [u8]`).
+    #[inline]
+    pub const fn from_file(path: &'static str) -> Self {
+        Self::file(None, path, |data| Ok(data.leak()))
+    }
+
+    /// Creates a handle to file which is read as a byte array (`&'static [u8]`), which is located in `manifest_dir` and can be rebased onto the runtime root.
+    #[inline]
+    pub const fn from_manifest_file(manifest_dir: &'static str, path: &'static str) -> Self {
+        Self::file(Some(manifest_dir), path, |data| Ok(data.leak()))
+    }
+}
+
+impl HotReload<str> {
+    /// Creates a handle to a utf8-encoded file which is read as a string slice (`&'static str`).
+    #[inline]
+    pub const fn from_file(path: &'static str) -> Self {
+        Self::file(None, path, |data| {
+            String::from_utf8(data).map(|s| &*s.leak()).map_err(|err| err.to_string())
+        })
+    }
+
+    /// Creates a handle to utf8-encoded file which is read as a string slice (`&'static str`), which is located in `manifest_dir` and can be rebased onto the runtime root.
+    #[inline]
+    pub const fn from_manifest_file(manifest_dir: &'static str, path: &'static str) -> Self {
+        Self::file(Some(manifest_dir), path, |data| {
+            String::from_utf8(data).map(|s| &*s.leak()).map_err(|err| err.to_string())
+        })
+    }
+}
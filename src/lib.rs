@@ -66,10 +66,115 @@ let data: &'static [u8] = *TEST;
 
 Also, private items (without `pub`) and public items (with `pub*`) cannot be put together.
 
+## Hot Reloading
+
+Items declared with the `hot` keyword are `HotReload` handles instead of `LazyLock` instances. When you are not using the **release** profile, the `get` method of a `HotReload` handle reads the file again whenever its modification time changes, so you can edit templates or shaders without restarting your program.
+When you are using the **release** profile, the data is still included by `include_bytes` and `include_str` macros and never changes.
+
+```rust
+use lazy_static_include::*;
+
+lazy_static_include_str! {
+    /// doc
+    hot TEST => "data/test.txt",
+}
+
+lazy_static_include_bytes! {
+    /// doc
+    pub hot TEST2 => ("data", "test-2.txt"),
+}
+
+assert_eq!("This is just a test text.", TEST.get());
+assert_eq!("Some text...".as_bytes(), TEST2.get());
+```
+
+Every reloaded version of the data is leaked, so hot reloading is only intended to be used during development. Also, items with the `hot` keyword and items without it cannot be put together.
+
+## Fallible Loading
+
+Items declared with the `try` keyword are `LazyLock<Result<&'static [u8], IncludeError>>` and `LazyLock<Result<&'static str, IncludeError>>` instances. When you are not using the **release** profile, a file which cannot be read or is not utf8-encoded results in an `IncludeError` instead of a panic, so you can log the error and degrade gracefully.
+
+```rust
+use lazy_static_include::*;
+
+lazy_static_include_str! {
+    /// doc
+    try TEST => "data/test.txt",
+}
+
+match TEST.as_ref() {
+    Ok(text) => assert_eq!("This is just a test text.", *text),
+    Err(err) => eprintln!("{err}"),
+}
+```
+
+Also, items with the `try` keyword and items without it cannot be put together.
+
+## Integrity Verification
+
+If you enable the `verify` feature, items declared with the `verify` keyword come with a `NAME_HASH` constant, which is the SHA-256 hash of the file computed at compile time in lowercase hexadecimal, in both debug and release builds. It can be used for cache-busting. When you are not using the **release** profile, the file read on first access is also hashed and compared with `NAME_HASH`, so a stale or tampered file results in a panic.
+
+```toml
+[dependencies.lazy-static-include]
+version = "*"
+features = ["verify"]
+```
+
+```rust,ignore
+use lazy_static_include::*;
+
+lazy_static_include_str! {
+    /// doc
+    verify TEST => "data/test.txt",
+}
+
+assert_eq!("This is just a test text.", *TEST);
+assert_eq!(64, TEST_HASH.len());
+```
+
+Also, items with the `verify` keyword and items without it cannot be put together.
+
+## Memory-Mapped Loading
+
+By default, when you are not using the **release** profile, the data of `lazy_static_include_bytes` and `lazy_static_include_str` macros are read into the heap and kept forever. If you include large files, you can enable the `mmap` feature to map the files into memory read-only instead, which does not double the peak memory usage. `lazy_static_include_str` still validates whether the mapped data is utf8-encoded.
+
+```toml
+[dependencies.lazy-static-include]
+version = "*"
+features = ["mmap"]
+```
+
+Do not modify or truncate the files while your program is running if the `mmap` feature is enabled.
+
+## Compressed Embedding
+
+If you include large files which compress well, you can enable the `deflate` feature and declare items with the `compressed` keyword in the `lazy_static_include_bytes` macro. When you are using the **release** profile, the files are compressed at compile time and decompressed into the heap on first access, which keeps the compiled executable binary file small. Otherwise, the files are read directly, just like other items.
+
+```toml
+[dependencies.lazy-static-include]
+version = "*"
+features = ["deflate"]
+```
+
+```rust,ignore
+use lazy_static_include::*;
+
+lazy_static_include_bytes! {
+    /// doc
+    compressed TEST => "data/test.txt",
+}
+
+assert_eq!("This is just a test text.".as_bytes(), *TEST);
+```
+
+Also, items with the `compressed` keyword and items without it cannot be put together.
+
 ## Include Array
 
 There is a special macro `lazy_static_include_array` which can include arrays from files.
-The array is fixed sized and can be one of these following types: `bool`, `char`, `usize`, `u8`, `u16`, `u32`, `u64`, `u128`, `isize`, `i8`, `i16`, `i32`, `i64`, `i128`, `f32`, `f64`, `&'static str`.
+The array is fixed sized and its element type can be one of these following types: `bool`, `char`, `usize`, `u8`, `u16`, `u32`, `u64`, `u128`, `isize`, `i8`, `i16`, `i32`, `i64`, `i128`, `f32`, `f64`, `&'static str`, `&'static [u8]` (byte strings like `b"..."` or `&[...]`), the `NonZero` integer types like `NonZeroU16` (written as plain integers which must not be zero), `Option` (written as `Some(...)` or `None`), or a fixed sized array or a tuple (up to 12 elements) of them, such as `[[f32; 4]; 256]` and `[(u16, u16, &'static str); 8]`. Byte literals like `b'x'` can be elements of `u8` arrays.
+Like what `include!` accepts, numeric elements can also be constant expressions with comments, hexadecimal, octal and binary literals, `as` casts, arithmetic and bitwise operators, and well-known constants such as `u32::MAX`, `u8::BITS` and `std::f64::consts::PI`. Arrays (including the outermost one) can also be array repeat expressions like `[0u8; 16]`.
+Integer literals like `5` can also be elements of float arrays, and so can `f32::NAN`, `f64::INFINITY` and `f64::NEG_INFINITY`.
 
 Also, the `lazy_static_include_array` macro includes data from files into the compiled executable binary file **only** when you are using the **release** profile.
 Be careful when you distribute your program.
@@ -100,6 +205,276 @@ assert_eq!("Hello", TEST2[1]);
 assert_eq!("哈囉", TEST2[2]);
 ```
 
+The length can also be omitted. In this case, the value is a `LazyLock<&'static [T]>` instance whose length is the number of elements in the file.
+
+```rust
+use lazy_static_include::*;
+
+lazy_static_include_array! {
+    /// doc
+    TEST: [u64] => "data/u64_array.txt",
+}
+
+assert_eq!(5, TEST.len());
+```
+
+When you are not using the **release** profile, an incorrect file makes the first dereference panic with an `ArrayIncludeError` message which tells you the index, line and column of the incorrect element.
+Items declared with the `try` keyword are `LazyLock<Result<[T; N], ArrayIncludeError>>` instances, so you can handle the error instead.
+
+```rust
+use lazy_static_include::*;
+
+lazy_static_include_array! {
+    /// doc
+    try TEST: [u64; 5] => "data/u64_array.txt",
+}
+
+assert_eq!(Ok([123, 456, 789, 1000, 500000000000]), *TEST);
+```
+
+## Custom Element Types
+
+Your own types can also be the element type of `lazy_static_include_array` by implementing the `IncludeElement` trait, which converts an expression in the file into a value when the file is loaded at runtime. When the file is embedded, it is included by the `include!` macro, so the paths in the file need to be resolvable where the macro is used.
+
+```rust
+use lazy_static_include::{expr::Expr, *};
+
+#[derive(Debug, PartialEq)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl IncludeElement for Color {
+    fn from_expr(expr: &Expr, index: usize) -> Result<Self, ArrayIncludeError> {
+        match expr.path() {
+            Some(["Color", "Red"]) => Ok(Color::Red),
+            Some(["Color", "Green"]) => Ok(Color::Green),
+            Some(["Color", "Blue"]) => Ok(Color::Blue),
+            _ => Err(Self::wrong_element_type(expr, index)),
+        }
+    }
+}
+
+lazy_static_include_array! {
+    /// doc
+    COLORS: [Color; 4] => "data/color_array.txt",
+}
+
+assert_eq!(Color::Blue, COLORS[2]);
+```
+
+## Include Map
+
+The `lazy_static_include_map` macro can include a file of `key = value` lines or a rust array of `(key, value)` tuples as a read-only `Map`, whose entries are sorted by their keys so that a key is looked up by a binary search. The keys need to be literals, and the values are parsed like the elements of `lazy_static_include_array`. When the file is embedded, the entries are sorted and checked for duplicate keys at compile time. Each item is declared with the type `Map<K, V>` and is a `LazyLock<lazy_static_include::Map<K, V>>`. Declaring an item as `HashMap<K, V>` is a compile error, because a `Map` is not a `std::collections::HashMap`.
+
+```rust
+use lazy_static_include::*;
+
+lazy_static_include_map! {
+    /// doc
+    MIME: Map<&'static str, &'static str> => "data/mime.txt",
+    /// doc
+    STATUS: Map<i16, &'static str> => "data/status_map.txt",
+}
+
+assert_eq!(Some(&"text/html"), MIME.get("html"));
+assert_eq!("Not Found", STATUS[&404]);
+```
+
+The content of `data/mime.txt` is like,
+
+```text
+// Extensions to MIME types
+"json" = "application/json"
+"html" = "text/html"
+```
+
+## Include Directory
+
+There is a special macro `lazy_static_include_dir` which can include all files in a directory (recursively) as a `LazyLock<BTreeMap<&'static str, &'static [u8]>>` or `LazyLock<BTreeMap<&'static str, &'static str>>` instance. The keys are the paths relative to the directory, separated by `/`.
+
+Also, the `lazy_static_include_dir` macro includes data from files into the compiled executable binary file **only** when you are using the **release** profile. Otherwise, the directory is read when the value is dereferenced for the first time.
+
+The paths used for `lazy_static_include_dir` are relative to **CARGO_MANIFEST_DIR**.
+
+```rust
+use lazy_static_include::*;
+
+lazy_static_include_dir! {
+    /// doc
+    TEST: str => "data/dir",
+}
+
+lazy_static_include_dir! {
+    /// doc
+    pub TEST2: [u8] => ("data", "dir"),
+}
+
+assert_eq!("Hello", TEST["hello.txt"]);
+assert_eq!("Rust", TEST["sub/rust.txt"]);
+
+assert_eq!("Hello".as_bytes(), TEST2["hello.txt"]);
+```
+
+## Include Glob
+
+If you enable the `glob` feature, the `lazy_static_include_glob` macro can include all files matching a glob pattern as a `LazyLock<&'static [(&'static str, &'static [u8])]>` or `LazyLock<&'static [(&'static str, &'static str)]>` instance. The entries are sorted by their paths, which are relative to **CARGO_MANIFEST_DIR** and separated by `/`.
+
+When you are not using the **release** profile, the pattern is evaluated when the value is dereferenced for the first time, so new files appear without recompiling. When you are using the **release** profile, each matched file is included by the `include_bytes` or `include_str` macro, and a pattern which matches nothing fails the compilation.
+
+```toml
+[dependencies.lazy-static-include]
+version = "*"
+features = ["glob"]
+```
+
+```rust,ignore
+use lazy_static_include::*;
+
+lazy_static_include_glob! {
+    /// doc
+    TEST => "data/dir/**/*.txt",
+}
+
+lazy_static_include_glob! {
+    /// doc
+    pub TEST2: str => "data/dir/**/*.txt",
+}
+
+assert_eq!(("data/dir/hello.txt", "Hello".as_bytes()), TEST[0]);
+assert_eq!(("data/dir/sub/rust.txt", "Rust"), TEST2[1]);
+```
+
+## Include JSON
+
+If you enable the `json` feature, the `lazy_static_include_json` macro can include a JSON file deserialized into any type which implements `serde::de::DeserializeOwned`. When you are using the **release** profile, the syntax of the file is checked at compile time, so a malformed file fails the build rather than at startup.
+
+```toml
+[dependencies.lazy-static-include]
+version = "*"
+features = ["json"]
+```
+
+```rust,ignore
+use lazy_static_include::*;
+
+#[derive(serde::Deserialize)]
+struct Config {
+    name:   String,
+    values: Vec<u32>,
+}
+
+lazy_static_include_json! {
+    /// doc
+    CONFIG: Config => "data/config.json",
+}
+
+assert_eq!("lazy-static-include", CONFIG.name);
+assert_eq!(vec![1, 2, 3], CONFIG.values);
+```
+
+## Include TOML and YAML
+
+Similarly, the `lazy_static_include_toml` macro and the `lazy_static_include_yaml` macro are available if you enable the `toml` feature and the `yaml` feature respectively.
+
+```toml
+[dependencies.lazy-static-include]
+version = "*"
+features = ["toml", "yaml"]
+```
+
+```rust,ignore
+use lazy_static_include::*;
+
+lazy_static_include_toml! {
+    /// doc
+    CONFIG: toml::Table => "data/config.toml",
+}
+
+lazy_static_include_yaml! {
+    /// doc
+    FIXTURE: serde_yaml_ng::Value => "data/config.yaml",
+}
+
+assert_eq!("lazy-static-include", CONFIG["name"].as_str().unwrap());
+assert_eq!("lazy-static-include", FIXTURE["name"].as_str().unwrap());
+```
+
+## Include CSV
+
+If you enable the `csv` feature, the `lazy_static_include_csv` macro can include a CSV file with a header row as a slice of records. The record type needs to derive the `CsvRecord` trait, which maps each field of the struct to the column with the same name and parses the data with `FromStr`. An incorrect field is reported with its line and column. If this crate is renamed or re-exported, add `#[csv_record(crate = path::to::lazy_static_include)]` to the struct so that the derived code can find the trait.
+
+```toml
+[dependencies.lazy-static-include]
+version = "*"
+features = ["csv"]
+```
+
+```rust,ignore
+use lazy_static_include::*;
+
+#[derive(CsvRecord)]
+struct Row {
+    id:    u32,
+    name:  String,
+    ratio: f64,
+}
+
+lazy_static_include_csv! {
+    /// doc
+    TABLE: [Row] => "data/table.csv",
+}
+
+assert_eq!(2, TABLE.len());
+assert_eq!("beta", TABLE[1].name);
+```
+
+## Mode Selection
+
+By default, files are read at runtime when the **release** profile is not used (or more precisely, when `debug_assertions` is enabled), and included into the compiled executable binary file otherwise. You can force one mode regardless of the profile by enabling the `runtime` feature or the `embed` feature. For example, a staging build with debug assertions enabled can still be self-contained. Since Cargo features are additive, the `runtime` feature takes precedence if both of them are enabled (e.g. by `--all-features`).
+
+```toml
+[dependencies.lazy-static-include]
+version = "*"
+features = ["embed"]
+```
+
+A single invocation of any macro in this crate can also override the mode by starting with `runtime;` or `embed;`.
+
+```rust
+use lazy_static_include::*;
+
+lazy_static_include_str! {
+    embed;
+    /// doc
+    TEST => "data/test.txt",
+}
+
+lazy_static_include_array! {
+    runtime;
+    /// doc
+    TEST2: [u64; 5] => "data/u64_array.txt",
+}
+
+assert_eq!("This is just a test text.", *TEST);
+assert_eq!(123, TEST2[0]);
+```
+
+## Runtime Root
+
+When files are read at runtime, their paths are resolved relative to the directory containing the manifest of your package at compile time. To run a debug build somewhere else (e.g. on a device or in a container), the paths can be rebased onto another directory by setting the `LAZY_STATIC_INCLUDE_ROOT` environment variable, or by calling the `set_root` function before any file is read. Absolute paths outside the manifest directory are not affected.
+
+```rust,no_run
+lazy_static_include::set_root("/opt/my-app").unwrap();
+
+lazy_static_include::lazy_static_include_str! {
+    /// doc
+    TEST => "data/test.txt", // reads /opt/my-app/data/test.txt at runtime
+}
+```
+
 ## Benchmark
 
 Using static mechanisms makes your program faster. See my benchmark result below (AMD Ryzen 9 3900X 12-Core Processor 12C/24T 3.90GHz, ran on 2020/07/02):
@@ -126,11 +501,59 @@ cargo bench
 */
 
 #[doc(hidden)]
-pub extern crate manifest_dir_macros;
+pub extern crate lazy_static_include_macros;
 
 #[doc(hidden)]
-pub extern crate syn;
+pub extern crate manifest_dir_macros;
 
+#[doc(hidden)]
+pub mod array;
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+#[doc(hidden)]
+pub mod deserialize;
+#[doc(hidden)]
+pub mod dir;
+mod error;
+#[doc(hidden)]
+pub mod eval;
+pub mod expr;
+mod hot_reload;
+#[doc(hidden)]
+pub mod load;
+mod macro_deflate;
 mod macro_include_array;
 mod macro_include_bytes;
+#[cfg(feature = "csv")]
+mod macro_include_csv;
+mod macro_include_dir;
+#[cfg(feature = "glob")]
+mod macro_include_glob;
+#[cfg(feature = "json")]
+mod macro_include_json;
+mod macro_include_map;
 mod macro_include_str;
+#[cfg(feature = "toml")]
+mod macro_include_toml;
+#[cfg(feature = "yaml")]
+mod macro_include_yaml;
+mod macro_mode;
+mod macro_verify;
+#[doc(hidden)]
+pub mod map;
+#[doc(hidden)]
+pub mod root;
+#[cfg(feature = "csv")]
+#[doc(hidden)]
+pub mod table;
+
+pub use array::IncludeElement;
+#[cfg(feature = "csv")]
+pub use error::CsvIncludeError;
+pub use error::{ArrayIncludeError, IncludeError};
+pub use hot_reload::HotReload;
+#[cfg(feature = "csv")]
+pub use lazy_static_include_macros::CsvRecord;
+pub use map::Map;
+pub use root::{root, set_root};
+#[cfg(feature = "csv")]
+pub use table::CsvRecord;
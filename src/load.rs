@@ -0,0 +1,94 @@
+#[cfg(feature = "verify")]
+use sha2::{Digest, Sha256};
+
+use crate::IncludeError;
+
+#[cfg(not(feature = "mmap"))]
+/// Reads a file as a byte array (`&'static [u8]`).
+pub fn read_bytes(path: &'static str) -> Result<&'static [u8], IncludeError> {
+    let data = std::fs::read(path).map_err(|err| IncludeError::Io {
+        path,
+        kind: err.kind(),
+    })?;
+
+    // Leak the file content to get a `&'static [u8]` reference, because the data needs to live as long as the program anyway.
+    Ok(data.leak())
+}
+
+#[cfg(feature = "mmap")]
+/// Maps a file into memory as a byte array (`&'static [u8]`).
+pub fn read_bytes(path: &'static str) -> Result<&'static [u8], IncludeError> {
+    let to_error = |err: std::io::Error| IncludeError::Io {
+        path,
+        kind: err.kind(),
+    };
+
+    let file = std::fs::File::open(path).map_err(to_error)?;
+
+    if file.metadata().map_err(to_error)?.len() == 0 {
+        return Ok(&[]);
+    }
+
+    // The mapping is read-only. Modifying or truncating the file while the program is running is undefined behavior, just like what other memory-mapped readers have.
+    let mmap = unsafe { memmap2::Mmap::map(&file) }.map_err(to_error)?;
+
+    // Leak the mapping to get a `&'static [u8]` reference, because the data needs to live as long as the program anyway.
+    Ok(Box::leak(Box::new(mmap)))
+}
+
+/// Reads a utf8-encoded file as a string slice (`&'static str`).
+pub fn read_str(path: &'static str) -> Result<&'static str, IncludeError> {
+    let data = read_bytes(path)?;
+
+    std::str::from_utf8(data).map_err(|err| IncludeError::Utf8 {
+        path,
+        valid_up_to: err.valid_up_to(),
+    })
+}
+
+/// Reads a utf8-encoded file as a `String`, which is not leaked.
+pub(crate) fn read_text(path: &'static str) -> Result<String, IncludeError> {
+    let data = std::fs::read(path).map_err(|err| IncludeError::Io {
+        path,
+        kind: err.kind(),
+    })?;
+
+    String::from_utf8(data).map_err(|err| IncludeError::Utf8 {
+        path,
+        valid_up_to: err.utf8_error().valid_up_to(),
+    })
+}
+
+#[cfg(feature = "verify")]
+/// Checks whether the SHA-256 hash of `data` read from `path` is `expected` in lowercase hexadecimal, and panics if it is not.
+pub fn verify<T: AsRef<[u8]> + ?Sized>(
+    path: &'static str,
+    data: &'static T,
+    expected: &'static str,
+) -> &'static T {
+    let found = format!("{:x}", Sha256::digest(data.as_ref()));
+
+    if found != expected {
+        panic!(
+            "the file {path:?} has been modified since compilation, expected the SHA-256 hash \
+             {expected} but found {found}"
+        );
+    }
+
+    data
+}
+
+#[cfg(feature = "deflate")]
+/// Decompresses deflate-compressed data embedded by the `compressed` items.
+pub fn inflate(data: &'static [u8]) -> &'static [u8] {
+    use std::io::Read;
+
+    let mut decoder = flate2::read::DeflateDecoder::new(data);
+    let mut decompressed = Vec::new();
+
+    // The data was compressed at compile time, so it is always valid.
+    decoder.read_to_end(&mut decompressed).unwrap();
+
+    // Leak the decompressed data to get a `&'static [u8]` reference, because the data needs to live as long as the program anyway.
+    decompressed.leak()
+}
@@ -0,0 +1,22 @@
+#[cfg(feature = "deflate")]
+/// Expands the `compressed` items of the `lazy_static_include_bytes` macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lazy_static_include_deflate {
+    ( $($t:tt)* ) => {
+        $($t)*
+    };
+}
+
+#[cfg(not(feature = "deflate"))]
+/// Rejects the `compressed` items of the `lazy_static_include_bytes` macro because the `deflate` feature is not enabled.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lazy_static_include_deflate {
+    ($($t:tt)*) => {
+        compile_error!(
+            "the `deflate` feature of the `lazy-static-include` crate needs to be enabled to use \
+             `compressed` items"
+        );
+    };
+}
@@ -1,77 +1,231 @@
-#[cfg(debug_assertions)]
 /// Includes a file as a reference to a byte array (`&'static [u8]`).
 ///
 /// The file is located relative to the directory containing the manifest of your package.
 #[macro_export]
 macro_rules! lazy_static_include_bytes {
-    ( @inner $path:expr ) => {
+    ( @runtime @inner $path:expr ) => {
+        match $crate::load::read_bytes($crate::root::rebase(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::not_directory_path!($path))) {
+            Ok(data) => data,
+            Err(err) => panic!("{}", err),
+        }
+    };
+    ( @runtime @try_inner $path:expr ) => {
+        $crate::load::read_bytes($crate::root::rebase(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::not_directory_path!($path)))
+    };
+    ( @runtime @verify_inner $path:expr ) => {
         {
-            let path = $crate::manifest_dir_macros::not_directory_path!($path);
+            let path = $crate::root::rebase(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::not_directory_path!($path));
+
+            match $crate::load::read_bytes(path) {
+                Ok(data) => $crate::load::verify(path, data, $crate::lazy_static_include_macros::hash!($path)),
+                Err(err) => panic!("{}", err),
+            }
+        }
+    };
+    ( @runtime @unit $(#[$attr: meta])* hot $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        static $name: $crate::HotReload<[u8]> = $crate::HotReload::<[u8]>::from_manifest_file(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::not_directory_path!($path));
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($v:tt)+))? hot $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: $crate::HotReload<[u8]> = $crate::HotReload::<[u8]>::from_manifest_file(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::not_directory_path!($path));
+    };
+    ( @runtime @unit $(#[$attr: meta])* try $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<Result<&'static [u8], $crate::IncludeError>> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_bytes!(@runtime @try_inner $path));
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($v:tt)+))? try $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<Result<&'static [u8], $crate::IncludeError>> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_bytes!(@runtime @try_inner $path));
+    };
+    ( @runtime @unit $(#[$attr: meta])* verify $name:ident => $path:expr ) => {
+        $crate::__lazy_static_include_verify! {
+            $crate::lazy_static_include_macros::include_hash!($name => $path);
+
+            $(#[$attr])*
+            static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_bytes!(@runtime @verify_inner $path));
+        }
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($v:tt)+))? verify $name:ident => $path:expr ) => {
+        $crate::__lazy_static_include_verify! {
+            $crate::lazy_static_include_macros::include_hash!(pub$(($($v)+))? $name => $path);
+
+            $(#[$attr])*
+            pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_bytes!(@runtime @verify_inner $path));
+        }
+    };
+    ( @runtime @unit $(#[$attr: meta])* compressed $name:ident => $path:expr ) => {
+        $crate::__lazy_static_include_deflate! {
+            $(#[$attr])*
+            static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_bytes!(@runtime @inner $path));
+        }
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($v:tt)+))? compressed $name:ident => $path:expr ) => {
+        $crate::__lazy_static_include_deflate! {
+            $(#[$attr])*
+            pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_bytes!(@runtime @inner $path));
+        }
+    };
+    ( @runtime @unit $(#[$attr: meta])* $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_bytes!(@runtime @inner $path));
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_bytes!(@runtime @inner $path));
+    };
+    ( @embed @unit $(#[$attr: meta])* hot $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        static $name: $crate::HotReload<[u8]> = $crate::HotReload::<[u8]>::embedded(include_bytes!($crate::manifest_dir_macros::path!($path)));
+    };
+    ( @embed @unit $(#[$attr: meta])* pub$(($($v:tt)+))? hot $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: $crate::HotReload<[u8]> = $crate::HotReload::<[u8]>::embedded(include_bytes!($crate::manifest_dir_macros::path!($path)));
+    };
+    ( @embed @unit $(#[$attr: meta])* try $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<Result<&'static [u8], $crate::IncludeError>> = ::std::sync::LazyLock::new(|| Ok(include_bytes!($crate::manifest_dir_macros::path!($path))));
+    };
+    ( @embed @unit $(#[$attr: meta])* pub$(($($v:tt)+))? try $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<Result<&'static [u8], $crate::IncludeError>> = ::std::sync::LazyLock::new(|| Ok(include_bytes!($crate::manifest_dir_macros::path!($path))));
+    };
+    ( @embed @unit $(#[$attr: meta])* verify $name:ident => $path:expr ) => {
+        $crate::__lazy_static_include_verify! {
+            $crate::lazy_static_include_macros::include_hash!($name => $path);
 
-            // Leak the file content to get a `&'static [u8]` reference, because the data needs to live as long as the program anyway.
-            let data: &'static [u8] = ::std::fs::read(path).unwrap().leak();
+            $(#[$attr])*
+            static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| include_bytes!($crate::manifest_dir_macros::path!($path)));
+        }
+    };
+    ( @embed @unit $(#[$attr: meta])* pub$(($($v:tt)+))? verify $name:ident => $path:expr ) => {
+        $crate::__lazy_static_include_verify! {
+            $crate::lazy_static_include_macros::include_hash!(pub$(($($v)+))? $name => $path);
 
-            data
+            $(#[$attr])*
+            pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| include_bytes!($crate::manifest_dir_macros::path!($path)));
+        }
+    };
+    ( @embed @unit $(#[$attr: meta])* compressed $name:ident => $path:expr ) => {
+        $crate::__lazy_static_include_deflate! {
+            $(#[$attr])*
+            static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| $crate::load::inflate($crate::lazy_static_include_macros::include_compressed!($path)));
+        }
+    };
+    ( @embed @unit $(#[$attr: meta])* pub$(($($v:tt)+))? compressed $name:ident => $path:expr ) => {
+        $crate::__lazy_static_include_deflate! {
+            $(#[$attr])*
+            pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| $crate::load::inflate($crate::lazy_static_include_macros::include_compressed!($path)));
         }
     };
-    ( @unit $(#[$attr: meta])* $name:ident => $path:expr ) => {
+    ( @embed @unit $(#[$attr: meta])* $name:ident => $path:expr ) => {
         $(#[$attr])*
-        static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_bytes!(@inner $path));
+        static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| include_bytes!($crate::manifest_dir_macros::path!($path)));
     };
-    ( @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident => $path:expr ) => {
+    ( @embed @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident => $path:expr ) => {
         $(#[$attr])*
-        pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_bytes!(@inner $path));
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| include_bytes!($crate::manifest_dir_macros::path!($path)));
     };
-    ( $($(#[$attr: meta])* $name:ident => $path:expr),* $(,)* ) => {
+    ( @$mode:ident $($(#[$attr: meta])* hot $name:ident => $path:expr),* $(,)* ) => {
         $(
             $crate::lazy_static_include_bytes! {
-                @unit
+                @$mode @unit
                 $(#[$attr])*
-                $name => $path
+                hot $name => $path
             }
         )*
     };
-    ( $($(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident => $path:expr),* $(,)* ) => {
+    ( @$mode:ident $($(#[$attr: meta])* pub$(($($v:tt)+))? hot $name:ident => $path:expr),* $(,)* ) => {
         $(
             $crate::lazy_static_include_bytes! {
-                @unit
+                @$mode @unit
                 $(#[$attr])*
-                pub$(($($v)+))? $name => $path
+                pub$(($($v)+))? hot $name => $path
             }
         )*
     };
-}
-
-#[cfg(not(debug_assertions))]
-/// Includes a file as a reference to a byte array (`&'static [u8]`).
-///
-/// The file is located relative to the directory containing the manifest of your package.
-#[macro_export]
-macro_rules! lazy_static_include_bytes {
-    ( @unit $(#[$attr: meta])* $name:ident => $path:expr ) => {
-        $(#[$attr])*
-        static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| include_bytes!($crate::manifest_dir_macros::path!($path)));
+    ( @$mode:ident $($(#[$attr: meta])* try $name:ident => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_bytes! {
+                @$mode @unit
+                $(#[$attr])*
+                try $name => $path
+            }
+        )*
     };
-    ( @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident => $path:expr ) => {
-        $(#[$attr])*
-        pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static [u8]> = ::std::sync::LazyLock::new(|| include_bytes!($crate::manifest_dir_macros::path!($path)));
+    ( @$mode:ident $($(#[$attr: meta])* pub$(($($v:tt)+))? try $name:ident => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_bytes! {
+                @$mode @unit
+                $(#[$attr])*
+                pub$(($($v)+))? try $name => $path
+            }
+        )*
+    };
+    ( @$mode:ident $($(#[$attr: meta])* verify $name:ident => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_bytes! {
+                @$mode @unit
+                $(#[$attr])*
+                verify $name => $path
+            }
+        )*
+    };
+    ( @$mode:ident $($(#[$attr: meta])* pub$(($($v:tt)+))? verify $name:ident => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_bytes! {
+                @$mode @unit
+                $(#[$attr])*
+                pub$(($($v)+))? verify $name => $path
+            }
+        )*
+    };
+    ( @$mode:ident $($(#[$attr: meta])* compressed $name:ident => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_bytes! {
+                @$mode @unit
+                $(#[$attr])*
+                compressed $name => $path
+            }
+        )*
+    };
+    ( @$mode:ident $($(#[$attr: meta])* pub$(($($v:tt)+))? compressed $name:ident => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_bytes! {
+                @$mode @unit
+                $(#[$attr])*
+                pub$(($($v)+))? compressed $name => $path
+            }
+        )*
     };
-    ( $($(#[$attr: meta])* $name:ident => $path:expr),* $(,)* ) => {
+    ( @$mode:ident $($(#[$attr: meta])* $name:ident => $path:expr),* $(,)* ) => {
         $(
             $crate::lazy_static_include_bytes! {
-                @unit
+                @$mode @unit
                 $(#[$attr])*
                 $name => $path
             }
         )*
     };
-    ( $($(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident => $path:expr),* $(,)* ) => {
+    ( @$mode:ident $($(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident => $path:expr),* $(,)* ) => {
         $(
             $crate::lazy_static_include_bytes! {
-                @unit
+                @$mode @unit
                 $(#[$attr])*
                 pub$(($($v)+))? $name => $path
             }
         )*
     };
+    ( @$mode:ident $($t:tt)* ) => {
+        compile_error!(concat!("incorrect input of the `", stringify!(lazy_static_include_bytes), "` macro"));
+    };
+    ( runtime; $($t:tt)* ) => {
+        $crate::lazy_static_include_bytes! { @runtime $($t)* }
+    };
+    ( embed; $($t:tt)* ) => {
+        $crate::lazy_static_include_bytes! { @embed $($t)* }
+    };
+    ( $($t:tt)* ) => {
+        $crate::__lazy_static_include_mode! { lazy_static_include_bytes { $($t)* } }
+    };
 }
@@ -0,0 +1,68 @@
+/// Includes a CSV file with a header row as a slice (`&'static [T]`) of records, whose type implements the `CsvRecord` trait.
+///
+/// The file is located relative to the directory containing the manifest of your package.
+#[macro_export]
+macro_rules! lazy_static_include_csv {
+    ( @runtime @inner $t:ty, $path:expr ) => {
+        {
+            let path = $crate::root::rebase(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::not_directory_path!($path));
+
+            match $crate::table::load_csv::<$t>(path) {
+                Ok(slice) => slice,
+                Err(err) => panic!("{}, file: {}", err, path),
+            }
+        }
+    };
+    ( @embed @inner $t:ty, $path:expr ) => {
+        match $crate::table::parse_csv::<$t>(include_str!($crate::manifest_dir_macros::path!($path))) {
+            Ok(slice) => slice,
+            Err(err) => panic!("{}, file: {}", err, $crate::manifest_dir_macros::path!($path)),
+        }
+    };
+    ( @runtime @unit $(#[$attr: meta])* $name:ident: [$t:ty] => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<&'static [$t]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_csv!(@runtime @inner $t, $path));
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: [$t:ty] => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static [$t]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_csv!(@runtime @inner $t, $path));
+    };
+    ( @embed @unit $(#[$attr: meta])* $name:ident: [$t:ty] => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<&'static [$t]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_csv!(@embed @inner $t, $path));
+    };
+    ( @embed @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: [$t:ty] => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static [$t]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_csv!(@embed @inner $t, $path));
+    };
+    ( @$mode:ident $($(#[$attr: meta])* $name:ident: [$t:ty] => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_csv! {
+                @$mode @unit
+                $(#[$attr])*
+                $name: [$t] => $path
+            }
+        )*
+    };
+    ( @$mode:ident $($(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: [$t:ty] => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_csv! {
+                @$mode @unit
+                $(#[$attr])*
+                pub$(($($v)+))? $name: [$t] => $path
+            }
+        )*
+    };
+    ( @$mode:ident $($t:tt)* ) => {
+        compile_error!(concat!("incorrect input of the `", stringify!(lazy_static_include_csv), "` macro"));
+    };
+    ( runtime; $($t:tt)* ) => {
+        $crate::lazy_static_include_csv! { @runtime $($t)* }
+    };
+    ( embed; $($t:tt)* ) => {
+        $crate::lazy_static_include_csv! { @embed $($t)* }
+    };
+    ( $($t:tt)* ) => {
+        $crate::__lazy_static_include_mode! { lazy_static_include_csv { $($t)* } }
+    };
+}
@@ -0,0 +1,74 @@
+/// Includes all files in a directory as a map from their relative paths (separated by `/`) to byte arrays (`&'static [u8]`) or string slices (`&'static str`).
+///
+/// The directory is located relative to the directory containing the manifest of your package.
+#[macro_export]
+macro_rules! lazy_static_include_dir {
+    ( @runtime @inner [u8], $path:expr ) => {
+        $crate::dir::load_dir_bytes($crate::root::rebase(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::directory_path!($path)))
+    };
+    ( @runtime @inner str, $path:expr ) => {
+        $crate::dir::load_dir_str($crate::root::rebase(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::directory_path!($path)))
+    };
+    ( @runtime @unit $(#[$attr: meta])* $name:ident: [u8] => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<::std::collections::BTreeMap<&'static str, &'static [u8]>> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_dir!(@runtime @inner [u8], $path));
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: [u8] => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<::std::collections::BTreeMap<&'static str, &'static [u8]>> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_dir!(@runtime @inner [u8], $path));
+    };
+    ( @runtime @unit $(#[$attr: meta])* $name:ident: str => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<::std::collections::BTreeMap<&'static str, &'static str>> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_dir!(@runtime @inner str, $path));
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: str => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<::std::collections::BTreeMap<&'static str, &'static str>> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_dir!(@runtime @inner str, $path));
+    };
+    ( @embed @unit $(#[$attr: meta])* $name:ident: [u8] => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<::std::collections::BTreeMap<&'static str, &'static [u8]>> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_macros::include_dir!(bytes, $path).iter().copied().collect());
+    };
+    ( @embed @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: [u8] => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<::std::collections::BTreeMap<&'static str, &'static [u8]>> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_macros::include_dir!(bytes, $path).iter().copied().collect());
+    };
+    ( @embed @unit $(#[$attr: meta])* $name:ident: str => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<::std::collections::BTreeMap<&'static str, &'static str>> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_macros::include_dir!(str, $path).iter().copied().collect());
+    };
+    ( @embed @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: str => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<::std::collections::BTreeMap<&'static str, &'static str>> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_macros::include_dir!(str, $path).iter().copied().collect());
+    };
+    ( @$mode:ident $($(#[$attr: meta])* $name:ident: $t:tt => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_dir! {
+                @$mode @unit
+                $(#[$attr])*
+                $name: $t => $path
+            }
+        )*
+    };
+    ( @$mode:ident $($(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: $t:tt => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_dir! {
+                @$mode @unit
+                $(#[$attr])*
+                pub$(($($v)+))? $name: $t => $path
+            }
+        )*
+    };
+    ( @$mode:ident $($t:tt)* ) => {
+        compile_error!(concat!("incorrect input of the `", stringify!(lazy_static_include_dir), "` macro"));
+    };
+    ( runtime; $($t:tt)* ) => {
+        $crate::lazy_static_include_dir! { @runtime $($t)* }
+    };
+    ( embed; $($t:tt)* ) => {
+        $crate::lazy_static_include_dir! { @embed $($t)* }
+    };
+    ( $($t:tt)* ) => {
+        $crate::__lazy_static_include_mode! { lazy_static_include_dir { $($t)* } }
+    };
+}
@@ -0,0 +1,92 @@
+/// Includes all files matching a glob pattern as a slice of `(path, data)` entries sorted by their paths. The data are byte arrays (`&'static [u8]`) by default, or string slices (`&'static str`).
+///
+/// The pattern and the paths of the entries are relative to the directory containing the manifest of your package.
+#[macro_export]
+macro_rules! lazy_static_include_glob {
+    ( @runtime @inner [u8], $pattern:expr ) => {
+        $crate::dir::load_glob_bytes($crate::root::rebase(env!("CARGO_MANIFEST_DIR"), env!("CARGO_MANIFEST_DIR")), $pattern)
+    };
+    ( @runtime @inner str, $pattern:expr ) => {
+        $crate::dir::load_glob_str($crate::root::rebase(env!("CARGO_MANIFEST_DIR"), env!("CARGO_MANIFEST_DIR")), $pattern)
+    };
+    ( @runtime @unit $(#[$attr: meta])* $name:ident: [u8] => $pattern:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<&'static [(&'static str, &'static [u8])]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_glob!(@runtime @inner [u8], $pattern));
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: [u8] => $pattern:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static [(&'static str, &'static [u8])]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_glob!(@runtime @inner [u8], $pattern));
+    };
+    ( @runtime @unit $(#[$attr: meta])* $name:ident: str => $pattern:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<&'static [(&'static str, &'static str)]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_glob!(@runtime @inner str, $pattern));
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: str => $pattern:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static [(&'static str, &'static str)]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_glob!(@runtime @inner str, $pattern));
+    };
+    ( @embed @unit $(#[$attr: meta])* $name:ident: [u8] => $pattern:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<&'static [(&'static str, &'static [u8])]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_macros::include_glob!(bytes, $pattern));
+    };
+    ( @embed @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: [u8] => $pattern:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static [(&'static str, &'static [u8])]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_macros::include_glob!(bytes, $pattern));
+    };
+    ( @embed @unit $(#[$attr: meta])* $name:ident: str => $pattern:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<&'static [(&'static str, &'static str)]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_macros::include_glob!(str, $pattern));
+    };
+    ( @embed @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: str => $pattern:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static [(&'static str, &'static str)]> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_macros::include_glob!(str, $pattern));
+    };
+    ( @$mode:ident $($(#[$attr: meta])* $name:ident: $t:tt => $pattern:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_glob! {
+                @$mode @unit
+                $(#[$attr])*
+                $name: $t => $pattern
+            }
+        )*
+    };
+    ( @$mode:ident $($(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: $t:tt => $pattern:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_glob! {
+                @$mode @unit
+                $(#[$attr])*
+                pub$(($($v)+))? $name: $t => $pattern
+            }
+        )*
+    };
+    ( @$mode:ident $($(#[$attr: meta])* $name:ident => $pattern:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_glob! {
+                @$mode @unit
+                $(#[$attr])*
+                $name: [u8] => $pattern
+            }
+        )*
+    };
+    ( @$mode:ident $($(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident => $pattern:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_glob! {
+                @$mode @unit
+                $(#[$attr])*
+                pub$(($($v)+))? $name: [u8] => $pattern
+            }
+        )*
+    };
+    ( @$mode:ident $($t:tt)* ) => {
+        compile_error!(concat!("incorrect input of the `", stringify!(lazy_static_include_glob), "` macro"));
+    };
+    ( runtime; $($t:tt)* ) => {
+        $crate::lazy_static_include_glob! { @runtime $($t)* }
+    };
+    ( embed; $($t:tt)* ) => {
+        $crate::lazy_static_include_glob! { @embed $($t)* }
+    };
+    ( $($t:tt)* ) => {
+        $crate::__lazy_static_include_mode! { lazy_static_include_glob { $($t)* } }
+    };
+}
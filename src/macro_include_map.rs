@@ -0,0 +1,70 @@
+/// Includes a file containing a rust array of `(key, value)` tuples or `key = value` lines as a read-only `Map`, whose entries are sorted by their keys. The keys need to be literals and the values are parsed like the elements of the `lazy_static_include_array` macro. The entries are sorted at compile time when the file is embedded.
+///
+/// Each item is declared as `NAME: Map<K, V> => path` and is a `LazyLock<lazy_static_include::Map<K, V>>`. Declaring an item as `HashMap<K, V>` is a compile error, because the item would not be a `std::collections::HashMap`.
+///
+/// The file is located relative to the directory containing the manifest of your package.
+#[macro_export]
+macro_rules! lazy_static_include_map {
+    ( @runtime @inner $k:ty, $v:ty, $path:expr ) => {
+        {
+            let path = $crate::root::rebase(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::not_directory_path!($path));
+
+            match $crate::map::load_map::<$k, $v>(path) {
+                Ok(map) => map,
+                Err(err) => panic!("{}, file: {}", err, path),
+            }
+        }
+    };
+    ( @runtime @unit $(#[$attr: meta])* $name:ident: Map<$k:ty, $v:ty> => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<$crate::Map<$k, $v>> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_map!(@runtime @inner $k, $v, $path));
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($vis:tt)+))? $name:ident: Map<$k:ty, $v:ty> => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($vis)+))? static $name: ::std::sync::LazyLock<$crate::Map<$k, $v>> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_map!(@runtime @inner $k, $v, $path));
+    };
+    ( @embed @unit $(#[$attr: meta])* $name:ident: Map<$k:ty, $v:ty> => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<$crate::Map<$k, $v>> = ::std::sync::LazyLock::new(|| $crate::Map::from_sorted($crate::lazy_static_include_macros::include_map!([($k, $v)], $path)));
+    };
+    ( @embed @unit $(#[$attr: meta])* pub$(($($vis:tt)+))? $name:ident: Map<$k:ty, $v:ty> => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($vis)+))? static $name: ::std::sync::LazyLock<$crate::Map<$k, $v>> = ::std::sync::LazyLock::new(|| $crate::Map::from_sorted($crate::lazy_static_include_macros::include_map!([($k, $v)], $path)));
+    };
+    ( @$mode:ident @unit $(#[$attr: meta])* $name:ident: HashMap<$k:ty, $v:ty> => $path:expr ) => {
+        compile_error!(concat!("`", stringify!($name), "` cannot be a `HashMap`, declare it as `Map<K, V>` instead, which is a sorted slice looked up by binary search"));
+    };
+    ( @$mode:ident @unit $(#[$attr: meta])* pub$(($($vis:tt)+))? $name:ident: HashMap<$k:ty, $v:ty> => $path:expr ) => {
+        compile_error!(concat!("`", stringify!($name), "` cannot be a `HashMap`, declare it as `Map<K, V>` instead, which is a sorted slice looked up by binary search"));
+    };
+    ( @$mode:ident $($(#[$attr: meta])* $name:ident: $map:ident<$k:ty, $v:ty> => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_map! {
+                @$mode @unit
+                $(#[$attr])*
+                $name: $map<$k, $v> => $path
+            }
+        )*
+    };
+    ( @$mode:ident $($(#[$attr: meta])* pub$(($($vis:tt)+))? $name:ident: $map:ident<$k:ty, $v:ty> => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_map! {
+                @$mode @unit
+                $(#[$attr])*
+                pub$(($($vis)+))? $name: $map<$k, $v> => $path
+            }
+        )*
+    };
+    ( @$mode:ident $($t:tt)* ) => {
+        compile_error!(concat!("incorrect input of the `", stringify!(lazy_static_include_map), "` macro"));
+    };
+    ( runtime; $($t:tt)* ) => {
+        $crate::lazy_static_include_map! { @runtime $($t)* }
+    };
+    ( embed; $($t:tt)* ) => {
+        $crate::lazy_static_include_map! { @embed $($t)* }
+    };
+    ( $($t:tt)* ) => {
+        $crate::__lazy_static_include_mode! { lazy_static_include_map { $($t)* } }
+    };
+}
@@ -1,77 +1,189 @@
-#[cfg(debug_assertions)]
 /// Includes a utf8-encoded file as a string slice (`&'static str`).
 ///
 /// The file is located relative to the directory containing the manifest of your package.
 #[macro_export]
 macro_rules! lazy_static_include_str {
-    ( @inner $path:expr ) => {
+    ( @runtime @inner $path:expr ) => {
+        match $crate::load::read_str($crate::root::rebase(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::not_directory_path!($path))) {
+            Ok(text) => text,
+            Err(err) => panic!("{}", err),
+        }
+    };
+    ( @runtime @try_inner $path:expr ) => {
+        $crate::load::read_str($crate::root::rebase(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::not_directory_path!($path)))
+    };
+    ( @runtime @verify_inner $path:expr ) => {
         {
-            let path = $crate::manifest_dir_macros::not_directory_path!($path);
+            let path = $crate::root::rebase(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::not_directory_path!($path));
+
+            match $crate::load::read_str(path) {
+                Ok(data) => $crate::load::verify(path, data, $crate::lazy_static_include_macros::hash!($path)),
+                Err(err) => panic!("{}", err),
+            }
+        }
+    };
+    ( @runtime @unit $(#[$attr: meta])* hot $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        static $name: $crate::HotReload<str> = $crate::HotReload::<str>::from_manifest_file(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::not_directory_path!($path));
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($v:tt)+))? hot $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: $crate::HotReload<str> = $crate::HotReload::<str>::from_manifest_file(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::not_directory_path!($path));
+    };
+    ( @runtime @unit $(#[$attr: meta])* try $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<Result<&'static str, $crate::IncludeError>> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_str!(@runtime @try_inner $path));
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($v:tt)+))? try $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<Result<&'static str, $crate::IncludeError>> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_str!(@runtime @try_inner $path));
+    };
+    ( @runtime @unit $(#[$attr: meta])* verify $name:ident => $path:expr ) => {
+        $crate::__lazy_static_include_verify! {
+            $crate::lazy_static_include_macros::include_hash!($name => $path);
+
+            $(#[$attr])*
+            static $name: ::std::sync::LazyLock<&'static str> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_str!(@runtime @verify_inner $path));
+        }
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($v:tt)+))? verify $name:ident => $path:expr ) => {
+        $crate::__lazy_static_include_verify! {
+            $crate::lazy_static_include_macros::include_hash!(pub$(($($v)+))? $name => $path);
+
+            $(#[$attr])*
+            pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static str> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_str!(@runtime @verify_inner $path));
+        }
+    };
+    ( @runtime @unit $(#[$attr: meta])* $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<&'static str> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_str!(@runtime @inner $path));
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static str> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_str!(@runtime @inner $path));
+    };
+    ( @embed @unit $(#[$attr: meta])* hot $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        static $name: $crate::HotReload<str> = $crate::HotReload::<str>::embedded(include_str!($crate::manifest_dir_macros::path!($path)));
+    };
+    ( @embed @unit $(#[$attr: meta])* pub$(($($v:tt)+))? hot $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: $crate::HotReload<str> = $crate::HotReload::<str>::embedded(include_str!($crate::manifest_dir_macros::path!($path)));
+    };
+    ( @embed @unit $(#[$attr: meta])* try $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<Result<&'static str, $crate::IncludeError>> = ::std::sync::LazyLock::new(|| Ok(include_str!($crate::manifest_dir_macros::path!($path))));
+    };
+    ( @embed @unit $(#[$attr: meta])* pub$(($($v:tt)+))? try $name:ident => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<Result<&'static str, $crate::IncludeError>> = ::std::sync::LazyLock::new(|| Ok(include_str!($crate::manifest_dir_macros::path!($path))));
+    };
+    ( @embed @unit $(#[$attr: meta])* verify $name:ident => $path:expr ) => {
+        $crate::__lazy_static_include_verify! {
+            $crate::lazy_static_include_macros::include_hash!($name => $path);
 
-            // Leak the file content to get a `&'static str` reference, because the data needs to live as long as the program anyway.
-            let text: &'static str = ::std::fs::read_to_string(path).unwrap().leak();
+            $(#[$attr])*
+            static $name: ::std::sync::LazyLock<&'static str> = ::std::sync::LazyLock::new(|| include_str!($crate::manifest_dir_macros::path!($path)));
+        }
+    };
+    ( @embed @unit $(#[$attr: meta])* pub$(($($v:tt)+))? verify $name:ident => $path:expr ) => {
+        $crate::__lazy_static_include_verify! {
+            $crate::lazy_static_include_macros::include_hash!(pub$(($($v)+))? $name => $path);
 
-            text
+            $(#[$attr])*
+            pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static str> = ::std::sync::LazyLock::new(|| include_str!($crate::manifest_dir_macros::path!($path)));
         }
     };
-    ( @unit $(#[$attr: meta])* $name:ident => $path:expr ) => {
+    ( @embed @unit $(#[$attr: meta])* $name:ident => $path:expr ) => {
         $(#[$attr])*
-        static $name: ::std::sync::LazyLock<&'static str> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_str!(@inner $path));
+        static $name: ::std::sync::LazyLock<&'static str> = ::std::sync::LazyLock::new(|| include_str!($crate::manifest_dir_macros::path!($path)));
     };
-    ( @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident => $path:expr ) => {
+    ( @embed @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident => $path:expr ) => {
         $(#[$attr])*
-        pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static str> = ::std::sync::LazyLock::new(|| $crate::lazy_static_include_str!(@inner $path));
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static str> = ::std::sync::LazyLock::new(|| include_str!($crate::manifest_dir_macros::path!($path)));
     };
-    ( $($(#[$attr: meta])* $name:ident => $path:expr),* $(,)* ) => {
+    ( @$mode:ident $($(#[$attr: meta])* hot $name:ident => $path:expr),* $(,)* ) => {
         $(
             $crate::lazy_static_include_str! {
-                @unit
+                @$mode @unit
                 $(#[$attr])*
-                $name => $path
+                hot $name => $path
             }
         )*
     };
-    ( $($(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident => $path:expr),* $(,)* ) => {
+    ( @$mode:ident $($(#[$attr: meta])* pub$(($($v:tt)+))? hot $name:ident => $path:expr),* $(,)* ) => {
         $(
             $crate::lazy_static_include_str! {
-                @unit
+                @$mode @unit
                 $(#[$attr])*
-                pub$(($($v)+))? $name => $path
+                pub$(($($v)+))? hot $name => $path
             }
         )*
     };
-}
-
-#[cfg(not(debug_assertions))]
-/// Includes a utf8-encoded file as a string slice (`&'static str`).
-///
-/// The file is located relative to the directory containing the manifest of your package.
-#[macro_export]
-macro_rules! lazy_static_include_str {
-    ( @unit $(#[$attr: meta])* $name:ident => $path:expr ) => {
-        $(#[$attr])*
-        static $name: ::std::sync::LazyLock<&'static str> = ::std::sync::LazyLock::new(|| include_str!($crate::manifest_dir_macros::path!($path)));
+    ( @$mode:ident $($(#[$attr: meta])* try $name:ident => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_str! {
+                @$mode @unit
+                $(#[$attr])*
+                try $name => $path
+            }
+        )*
     };
-    ( @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident => $path:expr ) => {
-        $(#[$attr])*
-        pub$(($($v)+))? static $name: ::std::sync::LazyLock<&'static str> = ::std::sync::LazyLock::new(|| include_str!($crate::manifest_dir_macros::path!($path)));
+    ( @$mode:ident $($(#[$attr: meta])* pub$(($($v:tt)+))? try $name:ident => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_str! {
+                @$mode @unit
+                $(#[$attr])*
+                pub$(($($v)+))? try $name => $path
+            }
+        )*
+    };
+    ( @$mode:ident $($(#[$attr: meta])* verify $name:ident => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_str! {
+                @$mode @unit
+                $(#[$attr])*
+                verify $name => $path
+            }
+        )*
     };
-    ( $($(#[$attr: meta])* $name:ident => $path:expr),* $(,)* ) => {
+    ( @$mode:ident $($(#[$attr: meta])* pub$(($($v:tt)+))? verify $name:ident => $path:expr),* $(,)* ) => {
         $(
             $crate::lazy_static_include_str! {
-                @unit
+                @$mode @unit
+                $(#[$attr])*
+                pub$(($($v)+))? verify $name => $path
+            }
+        )*
+    };
+    ( @$mode:ident $($(#[$attr: meta])* $name:ident => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_str! {
+                @$mode @unit
                 $(#[$attr])*
                 $name => $path
             }
         )*
     };
-    ( $($(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident => $path:expr),* $(,)* ) => {
+    ( @$mode:ident $($(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident => $path:expr),* $(,)* ) => {
         $(
             $crate::lazy_static_include_str! {
-                @unit
+                @$mode @unit
                 $(#[$attr])*
                 pub$(($($v)+))? $name => $path
             }
         )*
     };
+    ( @$mode:ident $($t:tt)* ) => {
+        compile_error!(concat!("incorrect input of the `", stringify!(lazy_static_include_str), "` macro"));
+    };
+    ( runtime; $($t:tt)* ) => {
+        $crate::lazy_static_include_str! { @runtime $($t)* }
+    };
+    ( embed; $($t:tt)* ) => {
+        $crate::lazy_static_include_str! { @embed $($t)* }
+    };
+    ( $($t:tt)* ) => {
+        $crate::__lazy_static_include_mode! { lazy_static_include_str { $($t)* } }
+    };
 }
@@ -0,0 +1,52 @@
+/// Includes a TOML file deserialized into any type which implements `serde::de::DeserializeOwned`. The syntax of the file is checked at compile time when it is embedded.
+///
+/// The file is located relative to the directory containing the manifest of your package.
+#[macro_export]
+macro_rules! lazy_static_include_toml {
+    ( @runtime @unit $(#[$attr: meta])* $name:ident: $t:ty => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<$t> = ::std::sync::LazyLock::new(|| $crate::deserialize::load_toml::<$t>($crate::root::rebase(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::not_directory_path!($path))));
+    };
+    ( @runtime @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: $t:ty => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<$t> = ::std::sync::LazyLock::new(|| $crate::deserialize::load_toml::<$t>($crate::root::rebase(env!("CARGO_MANIFEST_DIR"), $crate::manifest_dir_macros::not_directory_path!($path))));
+    };
+    ( @embed @unit $(#[$attr: meta])* $name:ident: $t:ty => $path:expr ) => {
+        $(#[$attr])*
+        static $name: ::std::sync::LazyLock<$t> = ::std::sync::LazyLock::new(|| $crate::deserialize::from_toml::<$t>($crate::lazy_static_include_macros::include_checked!(toml, $path), $crate::manifest_dir_macros::path!($path)));
+    };
+    ( @embed @unit $(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: $t:ty => $path:expr ) => {
+        $(#[$attr])*
+        pub$(($($v)+))? static $name: ::std::sync::LazyLock<$t> = ::std::sync::LazyLock::new(|| $crate::deserialize::from_toml::<$t>($crate::lazy_static_include_macros::include_checked!(toml, $path), $crate::manifest_dir_macros::path!($path)));
+    };
+    ( @$mode:ident $($(#[$attr: meta])* $name:ident: $t:ty => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_toml! {
+                @$mode @unit
+                $(#[$attr])*
+                $name: $t => $path
+            }
+        )*
+    };
+    ( @$mode:ident $($(#[$attr: meta])* pub$(($($v:tt)+))? $name:ident: $t:ty => $path:expr),* $(,)* ) => {
+        $(
+            $crate::lazy_static_include_toml! {
+                @$mode @unit
+                $(#[$attr])*
+                pub$(($($v)+))? $name: $t => $path
+            }
+        )*
+    };
+    ( @$mode:ident $($t:tt)* ) => {
+        compile_error!(concat!("incorrect input of the `", stringify!(lazy_static_include_toml), "` macro"));
+    };
+    ( runtime; $($t:tt)* ) => {
+        $crate::lazy_static_include_toml! { @runtime $($t)* }
+    };
+    ( embed; $($t:tt)* ) => {
+        $crate::lazy_static_include_toml! { @embed $($t)* }
+    };
+    ( $($t:tt)* ) => {
+        $crate::__lazy_static_include_mode! { lazy_static_include_toml { $($t)* } }
+    };
+}
@@ -0,0 +1,20 @@
+// The `runtime` feature wins over the `embed` feature when both of them are enabled, because features are additive.
+#[cfg(any(feature = "runtime", all(debug_assertions, not(feature = "embed"))))]
+/// Expands the items of a `lazy_static_include_*` macro in the runtime mode, which reads files when the values are dereferenced for the first time.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lazy_static_include_mode {
+    ( $mac:ident { $($t:tt)* } ) => {
+        $crate::$mac! { @runtime $($t)* }
+    };
+}
+
+#[cfg(not(any(feature = "runtime", all(debug_assertions, not(feature = "embed")))))]
+/// Expands the items of a `lazy_static_include_*` macro in the embedded mode, which includes files into the compiled executable binary file.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lazy_static_include_mode {
+    ( $mac:ident { $($t:tt)* } ) => {
+        $crate::$mac! { @embed $($t)* }
+    };
+}
@@ -0,0 +1,22 @@
+#[cfg(feature = "verify")]
+/// Expands the `verify` items of the `lazy_static_include_bytes` and `lazy_static_include_str` macros.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lazy_static_include_verify {
+    ( $($t:tt)* ) => {
+        $($t)*
+    };
+}
+
+#[cfg(not(feature = "verify"))]
+/// Rejects the `verify` items of the `lazy_static_include_bytes` and `lazy_static_include_str` macros because the `verify` feature is not enabled.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lazy_static_include_verify {
+    ($($t:tt)*) => {
+        compile_error!(
+            "the `verify` feature of the `lazy-static-include` crate needs to be enabled to use \
+             `verify` items"
+        );
+    };
+}
@@ -0,0 +1,219 @@
+use std::{
+    borrow::Borrow,
+    fmt::{self, Debug, Formatter},
+    ops::Index,
+};
+
+use crate::{
+    ArrayIncludeError, IncludeElement,
+    expr::{self, Expr, ExprKind, Lit},
+    load::read_text,
+};
+
+/// A read-only map included by the `lazy_static_include_map` macro. The entries are sorted by their keys, so a key is looked up by a binary search.
+pub struct Map<K: 'static, V: 'static> {
+    entries: &'static [(K, V)],
+}
+
+impl<K, V> Clone for Map<K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V> Copy for Map<K, V> {}
+
+impl<K, V> Map<K, V> {
+    /// Creates a map from entries which are sorted by their keys and have no duplicate keys.
+    #[doc(hidden)]
+    #[inline]
+    pub const fn from_sorted(entries: &'static [(K, V)]) -> Self {
+        Map {
+            entries,
+        }
+    }
+
+    /// The number of entries.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks whether the map has no entries.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The entries sorted by their keys.
+    #[inline]
+    pub const fn entries(&self) -> &'static [(K, V)] {
+        self.entries
+    }
+
+    /// Iterates over the entries in the order of their keys.
+    #[inline]
+    pub fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (&'static K, &'static V)> + ExactSizeIterator {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+
+    /// Iterates over the keys in order.
+    #[inline]
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &'static K> + ExactSizeIterator {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    /// Iterates over the values in the order of their keys.
+    #[inline]
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &'static V> + ExactSizeIterator {
+        self.entries.iter().map(|(_, value)| value)
+    }
+}
+
+impl<K: Ord, V> Map<K, V> {
+    /// Gets the entry of a key.
+    pub fn get_key_value<Q: Ord + ?Sized>(&self, key: &Q) -> Option<(&'static K, &'static V)>
+    where
+        K: Borrow<Q>, {
+        let entries = self.entries;
+
+        entries
+            .binary_search_by(|(k, _)| k.borrow().cmp(key))
+            .ok()
+            .map(|i| (&entries[i].0, &entries[i].1))
+    }
+
+    /// Gets the value of a key.
+    #[inline]
+    pub fn get<Q: Ord + ?Sized>(&self, key: &Q) -> Option<&'static V>
+    where
+        K: Borrow<Q>, {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    /// Checks whether the map has a key.
+    #[inline]
+    pub fn contains_key<Q: Ord + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>, {
+        self.get_key_value(key).is_some()
+    }
+}
+
+impl<K: Ord + Borrow<Q>, V, Q: Ord + ?Sized> Index<&Q> for Map<K, V> {
+    type Output = V;
+
+    /// Gets the value of a key. It panics if the key is not in the map.
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("the key is not in the map")
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for Map<K, V> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Checks whether an expression is a (possibly negated) literal. Keys need to be literals so that they can be sorted at compile time.
+fn is_literal(expr: &Expr) -> bool {
+    match &expr.kind {
+        ExprKind::Lit(_) => true,
+        ExprKind::Neg(expr) => {
+            matches!(expr.kind, ExprKind::Lit(Lit::Int { .. } | Lit::Float { .. }))
+        },
+        _ => false,
+    }
+}
+
+/// Parses the `(key, value)` expressions of a text containing a rust array of tuples or `key = value` lines.
+fn parse_entries(text: &str) -> Result<Vec<(Expr<'_>, Expr<'_>)>, ArrayIncludeError> {
+    if !expr::starts_with_array(text)? {
+        let mut entries = Vec::new();
+
+        for (i, line) in text.lines().enumerate() {
+            if let Some(entry) = expr::parse_entry(line, i + 1)? {
+                entries.push(entry);
+            }
+        }
+
+        return Ok(entries);
+    }
+
+    let expr = expr::parse(text)?;
+
+    let ExprKind::Array(array) = expr.kind else {
+        return Err(ArrayIncludeError::Syntax {
+            line:    expr.line,
+            column:  expr.column,
+            message: String::from("expected an array"),
+        });
+    };
+
+    array
+        .into_iter()
+        .map(|expr| match expr.kind {
+            ExprKind::Tuple(tuple) if tuple.len() == 2 => {
+                let mut tuple = tuple.into_iter();
+
+                Ok((tuple.next().unwrap(), tuple.next().unwrap()))
+            },
+            _ => Err(ArrayIncludeError::Syntax {
+                line:    expr.line,
+                column:  expr.column,
+                message: String::from("expected a `(key, value)` tuple"),
+            }),
+        })
+        .collect()
+}
+
+/// Parses a text containing a rust array of `(key, value)` tuples or `key = value` lines into a map.
+pub fn parse_map<K: IncludeElement + Ord, V: IncludeElement>(
+    text: &str,
+) -> Result<Map<K, V>, ArrayIncludeError> {
+    let exprs = parse_entries(text)?;
+
+    let mut entries = Vec::new();
+
+    for (index, (key, value)) in exprs.iter().enumerate() {
+        if !is_literal(key) {
+            return Err(ArrayIncludeError::Syntax {
+                line:    key.line,
+                column:  key.column,
+                message: String::from("expected a literal key"),
+            });
+        }
+
+        entries.push((K::from_expr(key, index)?, V::from_expr(value, index)?, key));
+    }
+
+    // The sort is stable, so the later one of two duplicate keys is reported.
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    if let Some(pair) = entries.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        let key = pair[1].2;
+
+        return Err(ArrayIncludeError::DuplicateKey {
+            key:    key.text.to_string(),
+            line:   key.line,
+            column: key.column,
+        });
+    }
+
+    let entries = entries.into_iter().map(|(key, value, _)| (key, value)).collect::<Vec<_>>();
+
+    // Leak the entries to get a `&'static [(K, V)]` reference, because the data needs to live as long as the program anyway.
+    Ok(Map::from_sorted(entries.leak()))
+}
+
+/// Reads a file containing a rust array of `(key, value)` tuples or `key = value` lines into a map.
+pub fn load_map<K: IncludeElement + Ord, V: IncludeElement>(
+    path: &'static str,
+) -> Result<Map<K, V>, ArrayIncludeError> {
+    parse_map(&read_text(path)?)
+}
@@ -0,0 +1,51 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+/// The name of the environment variable which sets the runtime root.
+const ROOT_ENV: &str = "LAZY_STATIC_INCLUDE_ROOT";
+
+static ROOT: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+/// Sets the directory onto which the paths relative to **CARGO_MANIFEST_DIR** are rebased when files are read at runtime. It overrides the `LAZY_STATIC_INCLUDE_ROOT` environment variable.
+///
+/// The root needs to be set before any file is read. Otherwise, the root cannot be changed anymore and the argument is returned as an error.
+pub fn set_root<P: Into<PathBuf>>(root: P) -> Result<(), PathBuf> {
+    let root = root.into();
+
+    let mut result = Err(root.clone());
+
+    ROOT.get_or_init(|| {
+        result = Ok(());
+
+        Some(root)
+    });
+
+    result
+}
+
+/// Gets the directory onto which the paths relative to **CARGO_MANIFEST_DIR** are rebased when files are read at runtime. It is set by the `set_root` function or the `LAZY_STATIC_INCLUDE_ROOT` environment variable.
+pub fn root() -> Option<&'static Path> {
+    ROOT.get_or_init(|| env::var_os(ROOT_ENV).map(PathBuf::from)).as_deref()
+}
+
+/// Rebases `path` onto the runtime root if it is located in `manifest_dir` and the runtime root is set.
+#[doc(hidden)]
+pub fn rebase(manifest_dir: &'static str, path: &'static str) -> &'static str {
+    let Some(root) = root() else {
+        return path;
+    };
+
+    match Path::new(path).strip_prefix(manifest_dir) {
+        Ok(relative_path) => {
+            let path =
+                if relative_path.as_os_str().is_empty() { root } else { &root.join(relative_path) };
+
+            // Leak the rebased path to get a `&'static str` reference, because the path is only computed once for each item.
+            path.to_string_lossy().into_owned().leak()
+        },
+        Err(_) => path,
+    }
+}
@@ -0,0 +1,71 @@
+use csv::{ReaderBuilder, StringRecord};
+
+use crate::{CsvIncludeError, load::read_text};
+
+/// Types which can be the record type of tables included by the `lazy_static_include_csv` macro. It can be derived for structs with named fields whose types implement `FromStr`.
+pub trait CsvRecord: Sized {
+    /// The names of the columns, which need to be in the header row.
+    fn columns() -> &'static [&'static str];
+
+    /// Converts the fields of a row, which are in the order of `columns`, into a record. The error is the index of the incorrect field and the error message.
+    fn from_fields(fields: &[&str]) -> Result<Self, (usize, String)>;
+}
+
+#[inline]
+fn line(record: &StringRecord) -> u64 {
+    record.position().map(|position| position.line()).unwrap_or_default()
+}
+
+fn syntax_error(err: csv::Error) -> CsvIncludeError {
+    CsvIncludeError::Syntax {
+        line:    err.position().map(|position| position.line()).unwrap_or_default(),
+        message: err.to_string(),
+    }
+}
+
+/// Parses a CSV text with a header row into a slice.
+pub fn parse_csv<T: CsvRecord>(text: &str) -> Result<&'static [T], CsvIncludeError> {
+    let mut reader = ReaderBuilder::new().from_reader(text.as_bytes());
+
+    let headers = reader.headers().map_err(syntax_error)?.clone();
+
+    let columns = T::columns();
+
+    // Map each column of the record type to the index of the field in the rows.
+    let indices = columns
+        .iter()
+        .map(|&column| {
+            headers.iter().position(|header| header == column).ok_or(
+                CsvIncludeError::MissingColumn {
+                    column,
+                },
+            )
+        })
+        .collect::<Result<Vec<usize>, CsvIncludeError>>()?;
+
+    let mut records = Vec::new();
+
+    for row in reader.records() {
+        let row = row.map_err(syntax_error)?;
+
+        let fields = indices.iter().map(|&i| row.get(i).unwrap_or_default()).collect::<Vec<&str>>();
+
+        let record =
+            T::from_fields(&fields).map_err(|(i, message)| CsvIncludeError::WrongField {
+                line: line(&row),
+                column: columns[i],
+                value: fields[i].to_string(),
+                message,
+            })?;
+
+        records.push(record);
+    }
+
+    // Leak the records to get a `&'static [T]` reference, because the data needs to live as long as the program anyway.
+    Ok(records.leak())
+}
+
+/// Reads a CSV file with a header row into a slice.
+pub fn load_csv<T: CsvRecord>(path: &'static str) -> Result<&'static [T], CsvIncludeError> {
+    parse_csv(&read_text(path)?)
+}
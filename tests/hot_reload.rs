@@ -0,0 +1,72 @@
+use std::{
+    fs::{self, File},
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+use lazy_static_include::HotReload;
+
+/// Makes sure the modification time changes even on file systems with a coarse timestamp granularity.
+fn touch(path: &Path, secs: u64) {
+    File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(SystemTime::now() + Duration::from_secs(secs))
+        .unwrap();
+}
+
+#[test]
+fn reload_modified_file() {
+    let path = std::env::temp_dir().join("lazy-static-include-hot-reload.txt");
+
+    fs::write(&path, "Hello").unwrap();
+
+    let hot = HotReload::<str>::from_file(path.to_str().unwrap().to_string().leak());
+
+    assert_eq!("Hello", hot.get());
+
+    fs::write(&path, "Hello world!").unwrap();
+    touch(&path, 10);
+
+    assert_eq!("Hello world!", hot.get());
+
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!("Hello world!", hot.get());
+}
+
+#[test]
+fn keep_last_content_of_invalid_file() {
+    let path = std::env::temp_dir().join("lazy-static-include-hot-reload-invalid.txt");
+
+    fs::write(&path, "Hello").unwrap();
+
+    let hot = HotReload::<str>::from_file(path.to_str().unwrap().to_string().leak());
+
+    assert_eq!("Hello", hot.get());
+
+    fs::write(&path, b"Hello \xFF").unwrap();
+    touch(&path, 10);
+
+    assert_eq!("Hello", hot.get());
+
+    fs::write(&path, "Hello world!").unwrap();
+    touch(&path, 20);
+
+    assert_eq!("Hello world!", hot.get());
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "invalid utf-8")]
+fn panic_on_invalid_first_load() {
+    let path = std::env::temp_dir().join("lazy-static-include-hot-reload-invalid-first.txt");
+
+    fs::write(&path, b"\xFF").unwrap();
+
+    let hot = HotReload::<str>::from_file(path.to_str().unwrap().to_string().leak());
+
+    let _ = hot.get();
+}
@@ -1,5 +1,5 @@
 use assert_eq_float::assert_eq_float;
-use lazy_static_include::*;
+use lazy_static_include::{expr::Expr, *};
 
 #[test]
 fn include_str() {
@@ -237,3 +237,993 @@ fn include_array_string() {
     assert_eq!("Hello", TEST[1]);
     assert_eq!("哈囉", TEST[2]);
 }
+
+#[test]
+fn include_str_hot() {
+    lazy_static_include_str! {
+        hot TEST => "data/test.txt",
+        hot TEST2 => "data/test-2.txt",
+    }
+
+    assert_eq!("This is just a test text.", TEST.get());
+    assert_eq!("Some text...", TEST2.get());
+}
+
+#[test]
+fn include_bytes_hot() {
+    lazy_static_include_bytes! {
+        hot TEST => "data/test.txt",
+        hot TEST2 => "data/test-2.txt",
+    }
+
+    assert_eq!("This is just a test text.".as_bytes(), TEST.get());
+    assert_eq!("Some text...".as_bytes(), TEST2.get());
+}
+
+#[test]
+fn include_str_try() {
+    lazy_static_include_str! {
+        try TEST => "data/test.txt",
+        try TEST2 => "data/test-2.txt",
+    }
+
+    assert_eq!(Ok("This is just a test text."), *TEST);
+    assert_eq!(Ok("Some text..."), *TEST2);
+}
+
+#[test]
+fn include_str_try_non_utf8() {
+    lazy_static_include_str! {
+        runtime;
+        try TEST => "data/non-utf8.txt",
+    }
+
+    match *TEST {
+        Err(IncludeError::Utf8 {
+            path,
+            valid_up_to,
+        }) => {
+            assert!(path.ends_with("non-utf8.txt"));
+            assert_eq!(5, valid_up_to);
+        },
+        _ => panic!("expected a utf8 error"),
+    }
+}
+
+#[test]
+fn include_bytes_try() {
+    lazy_static_include_bytes! {
+        try TEST => "data/test.txt",
+        try TEST2 => "data/test-2.txt",
+    }
+
+    assert_eq!(Ok("This is just a test text.".as_bytes()), *TEST);
+    assert_eq!(Ok("Some text...".as_bytes()), *TEST2);
+}
+
+#[cfg(feature = "verify")]
+#[test]
+fn include_str_verify() {
+    lazy_static_include_str! {
+        verify TEST => "data/test.txt",
+    }
+
+    assert_eq!("This is just a test text.", *TEST);
+    assert_eq!("15a3114a669e3912b9167f31136c9266d05fbafb604c3d9c3222d5be89887b7e", TEST_HASH);
+}
+
+#[cfg(feature = "verify")]
+#[test]
+fn include_bytes_verify() {
+    lazy_static_include_bytes! {
+        verify TEST => "data/test.txt",
+        verify TEST2 => "data/test-2.txt",
+    }
+
+    assert_eq!("This is just a test text.".as_bytes(), *TEST);
+    assert_eq!("Some text...".as_bytes(), *TEST2);
+    assert_eq!("15a3114a669e3912b9167f31136c9266d05fbafb604c3d9c3222d5be89887b7e", TEST_HASH);
+}
+
+#[cfg(feature = "deflate")]
+#[test]
+fn include_bytes_compressed() {
+    lazy_static_include_bytes! {
+        compressed TEST => "data/test.txt",
+        compressed TEST2 => "data/test-2.txt",
+    }
+
+    lazy_static_include_bytes! {
+        embed;
+        compressed TEST3 => "data/test.txt",
+    }
+
+    assert_eq!("This is just a test text.".as_bytes(), *TEST);
+    assert_eq!("Some text...".as_bytes(), *TEST2);
+    assert_eq!("This is just a test text.".as_bytes(), *TEST3);
+}
+
+#[test]
+fn include_array_try() {
+    lazy_static_include_array! {
+        try TEST: [u64; 5] => "data/u64_array.txt",
+    }
+
+    assert_eq!(Ok([123, 456, 789, 1000, 500000000000u64]), *TEST);
+}
+
+#[test]
+fn include_array_try_wrong_element_type() {
+    lazy_static_include_array! {
+        runtime;
+        try TEST: [bool; 3] => "data/bool_array-wrong.txt",
+    }
+
+    assert_eq!(
+        Err(ArrayIncludeError::WrongElementType {
+            index:       2,
+            expected:    "bool",
+            found_token: String::from("1"),
+            line:        4,
+            column:      5,
+        }),
+        *TEST
+    );
+}
+
+#[test]
+fn include_array_try_overflow() {
+    lazy_static_include_array! {
+        runtime;
+        try TEST: [u8; 3] => "data/u8_array-overflow.txt",
+    }
+
+    assert_eq!(
+        Err(ArrayIncludeError::Overflow {
+            index:    2,
+            value:    String::from("256"),
+            expected: "u8",
+            line:     1,
+            column:   8,
+        }),
+        *TEST
+    );
+}
+
+#[test]
+fn include_array_min() {
+    lazy_static_include_array! {
+        runtime;
+        TEST: [i8; 5] => "data/i8_array-min.txt",
+    }
+
+    lazy_static_include_array! {
+        embed;
+        TEST2: [i8; 5] => "data/i8_array-min.txt",
+    }
+
+    assert_eq!([-128, 127, -128, -128, -128], *TEST);
+    assert_eq!(*TEST2, *TEST);
+}
+
+#[test]
+#[should_panic(expected = "value -9 out of range for u8 at index 8 (line 4, col 11)")]
+fn include_array_negative_unsigned() {
+    lazy_static_include_array! {
+        runtime;
+        TEST: [u8; 9] => "data/u8_array-negative.txt",
+    }
+
+    let _ = TEST[0];
+}
+
+#[test]
+fn include_array_try_wrong_length() {
+    lazy_static_include_array! {
+        runtime;
+        try TEST: [u64; 4] => "data/u64_array.txt",
+    }
+
+    assert_eq!(
+        Err(ArrayIncludeError::WrongLength {
+            expected: 4, found: 5
+        }),
+        *TEST
+    );
+}
+
+#[test]
+fn include_array_try_shorter_length() {
+    lazy_static_include_array! {
+        runtime;
+        try TEST: [u64; 6] => "data/u64_array.txt",
+    }
+
+    assert_eq!(
+        Err(ArrayIncludeError::WrongLength {
+            expected: 6, found: 5
+        }),
+        *TEST
+    );
+}
+
+#[test]
+#[should_panic(expected = "incorrect length, expected 6 elements but found 5")]
+fn include_array_shorter_length() {
+    lazy_static_include_array! {
+        runtime;
+        TEST: [u64; 6] => "data/u64_array.txt",
+    }
+
+    let _ = TEST[0];
+}
+
+#[test]
+#[should_panic(expected = "incorrect element type, index = 2 (line 4, column 5), expected `bool` \
+                           but found `1`")]
+fn include_array_wrong_element_type() {
+    lazy_static_include_array! {
+        runtime;
+        TEST: [bool; 3] => "data/bool_array-wrong.txt",
+    }
+
+    let _ = TEST[0];
+}
+
+#[test]
+fn include_slice() {
+    lazy_static_include_array! {
+        TEST: [u64] => "data/u64_array.txt",
+        TEST2: [i32] => "data/i32_array-2.txt",
+    }
+
+    assert_eq!(&[123, 456, 789, 1000, 500000000000u64], *TEST);
+    assert_eq!(&[-1, -2, -3, -4, -5], *TEST2);
+}
+
+#[test]
+fn include_slice_string() {
+    lazy_static_include_array! {
+        TEST: [&'static str] => "data/string_array-2.txt",
+    }
+
+    assert_eq!(&["Hello world!", "Rust", "你好"], *TEST);
+}
+
+#[test]
+fn include_slice_try() {
+    lazy_static_include_array! {
+        try TEST: [f64] => "data/f64_array.txt",
+    }
+
+    assert_eq!(5, TEST.as_ref().unwrap().len());
+}
+
+#[test]
+fn include_array_nested() {
+    lazy_static_include_array! {
+        TEST: [[u8; 3]; 4] => "data/u8_palette.txt",
+        TEST2: [[[f32; 2]; 2]; 2] => "data/f32_matrix.txt",
+    }
+
+    assert_eq!([[255, 0, 0], [0, 255, 0], [0, 0, 255], [255, 255, 255]], *TEST);
+    assert_eq_float!(0.5f32, TEST2[0][0][1]);
+    assert_eq_float!(3.5f32, TEST2[1][1][1]);
+}
+
+#[test]
+fn include_slice_nested() {
+    lazy_static_include_array! {
+        TEST: [[u8; 3]] => "data/u8_palette.txt",
+    }
+
+    assert_eq!(4, TEST.len());
+    assert_eq!([0, 0, 255], TEST[2]);
+}
+
+#[test]
+fn include_array_try_nested_wrong_length() {
+    lazy_static_include_array! {
+        runtime;
+        try TEST: [[u8; 3]; 2] => "data/u8_palette-wrong.txt",
+    }
+
+    assert_eq!(
+        Err(ArrayIncludeError::WrongInnerLength {
+            index:    1,
+            expected: 3,
+            found:    2,
+            line:     3,
+            column:   5,
+        }),
+        *TEST
+    );
+}
+
+#[test]
+fn include_array_tuple() {
+    lazy_static_include_array! {
+        TEST: [(u16, u16, &'static str); 3] => "data/sprite_array.txt",
+    }
+
+    assert_eq!((0, 0, "player"), TEST[0]);
+    assert_eq!((32, 0, "enemy"), TEST[1]);
+    assert_eq!((0, 32, "coin"), TEST[2]);
+}
+
+#[test]
+fn include_slice_tuple() {
+    lazy_static_include_array! {
+        TEST: [(u16, u16, &'static str)] => "data/sprite_array.txt",
+    }
+
+    assert_eq!(3, TEST.len());
+    assert_eq!("coin", TEST[2].2);
+}
+
+#[test]
+fn include_array_bytes() {
+    lazy_static_include_array! {
+        TEST: [&'static [u8]; 3] => "data/bytes_array.txt",
+    }
+
+    assert_eq!(&[0u8, 1], TEST[0]);
+    assert_eq!(&[2u8, 3, 4], TEST[1]);
+    assert_eq!(b"abc", TEST[2]);
+}
+
+#[test]
+fn include_array_u8_byte_literal() {
+    lazy_static_include_array! {
+        TEST: [u8; 3] => "data/u8_array-2.txt",
+    }
+
+    assert_eq!(*b"ab\n", *TEST);
+}
+
+#[test]
+fn include_dir() {
+    lazy_static_include_dir! {
+        TEST: str => "data/dir",
+        TEST2: [u8] => ("data", "dir"),
+    }
+
+    assert_eq!(
+        vec![("hello.txt", "Hello"), ("sub/rust.txt", "Rust")],
+        TEST.clone().into_iter().collect::<Vec<_>>()
+    );
+    assert_eq!("Rust".as_bytes(), TEST2["sub/rust.txt"]);
+    assert_eq!(2, TEST2.len());
+}
+
+#[test]
+#[should_panic(expected = "invalid.txt\" is not utf8-encoded, invalid byte at offset 2")]
+fn include_dir_non_utf8() {
+    lazy_static_include_dir! {
+        runtime;
+        TEST: str => "data/dir-non-utf8",
+    }
+
+    let _ = TEST.len();
+}
+
+#[cfg(feature = "glob")]
+#[test]
+fn include_glob() {
+    lazy_static_include_glob! {
+        TEST => "data/dir/**/*.txt",
+        TEST2 => "data/*_array-2.txt",
+    }
+
+    assert_eq!(
+        [("data/dir/hello.txt", "Hello".as_bytes()), ("data/dir/sub/rust.txt", "Rust".as_bytes())],
+        *TEST
+    );
+    assert_eq!(
+        ["data/i32_array-2.txt", "data/string_array-2.txt", "data/u8_array-2.txt"],
+        *TEST2.iter().map(|(path, _)| *path).collect::<Vec<_>>()
+    );
+}
+
+#[cfg(feature = "glob")]
+#[test]
+fn include_glob_str() {
+    lazy_static_include_glob! {
+        TEST: str => "data/dir/**/*.txt",
+    }
+
+    assert_eq!([("data/dir/hello.txt", "Hello"), ("data/dir/sub/rust.txt", "Rust")], *TEST);
+}
+
+#[test]
+fn include_str_mode() {
+    lazy_static_include_str! {
+        embed;
+        TEST => "data/test.txt",
+    }
+
+    lazy_static_include_str! {
+        runtime;
+        TEST2 => "data/test-2.txt",
+    }
+
+    assert_eq!("This is just a test text.", *TEST);
+    assert_eq!("Some text...", *TEST2);
+}
+
+#[test]
+fn include_array_mode() {
+    lazy_static_include_array! {
+        embed;
+        TEST: [i32; 5] => "data/i32_array-2.txt",
+        TEST2: [i32] => "data/i32_array-2.txt",
+    }
+
+    lazy_static_include_array! {
+        runtime;
+        try TEST3: [i32; 5] => "data/i32_array-2.txt",
+    }
+
+    assert_eq!([-1, -2, -3, -4, -5], *TEST);
+    assert_eq!(&[-1, -2, -3, -4, -5], *TEST2);
+    assert_eq!(Ok([-1, -2, -3, -4, -5]), *TEST3);
+}
+
+#[cfg(feature = "glob")]
+#[test]
+fn include_glob_mode() {
+    lazy_static_include_glob! {
+        embed;
+        TEST: str => "data/dir/*.txt",
+    }
+
+    lazy_static_include_glob! {
+        runtime;
+        TEST2: str => "data/dir/*.txt",
+    }
+
+    assert_eq!(*TEST, *TEST2);
+}
+
+#[cfg(feature = "glob")]
+#[test]
+#[should_panic(expected = "invalid.txt\" is not utf8-encoded, invalid byte at offset 2")]
+fn include_glob_non_utf8() {
+    lazy_static_include_glob! {
+        runtime;
+        TEST: str => "data/dir-non-utf8/*.txt",
+    }
+
+    let _ = TEST.len();
+}
+
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+#[derive(Debug, PartialEq, serde::Deserialize)]
+struct Config {
+    name:   String,
+    values: Vec<u32>,
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn include_json() {
+    lazy_static_include_json! {
+        CONFIG: Config => "data/config.json",
+        VALUE: serde_json::Value => "data/config.json",
+    }
+
+    lazy_static_include_json! {
+        embed;
+        CONFIG2: Config => "data/config.json",
+    }
+
+    assert_eq!("lazy-static-include", CONFIG.name);
+    assert_eq!(vec![1, 2, 3], CONFIG.values);
+    assert_eq!(2, VALUE["values"][1]);
+    assert_eq!(*CONFIG, *CONFIG2);
+}
+
+#[cfg(feature = "json")]
+#[test]
+#[should_panic(expected = "trailing comma at line 3 column 24")]
+fn include_json_wrong() {
+    lazy_static_include_json! {
+        runtime;
+        CONFIG: Config => "data/config-wrong.json",
+    }
+
+    let _ = &*CONFIG;
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn include_toml() {
+    lazy_static_include_toml! {
+        CONFIG: Config => "data/config.toml",
+        VALUE: toml::Value => "data/config.toml",
+    }
+
+    lazy_static_include_toml! {
+        embed;
+        CONFIG2: Config => "data/config.toml",
+    }
+
+    assert_eq!("lazy-static-include", CONFIG.name);
+    assert_eq!(vec![1, 2, 3], CONFIG.values);
+    assert_eq!(2, VALUE["values"][1].as_integer().unwrap());
+    assert_eq!(*CONFIG, *CONFIG2);
+}
+
+#[cfg(feature = "toml")]
+#[test]
+#[should_panic(expected = "config-wrong.toml")]
+fn include_toml_wrong() {
+    lazy_static_include_toml! {
+        runtime;
+        CONFIG: Config => "data/config-wrong.toml",
+    }
+
+    let _ = &*CONFIG;
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn include_yaml() {
+    lazy_static_include_yaml! {
+        CONFIG: Config => "data/config.yaml",
+        VALUE: serde_yaml_ng::Value => "data/config.yaml",
+    }
+
+    lazy_static_include_yaml! {
+        embed;
+        CONFIG2: Config => "data/config.yaml",
+    }
+
+    assert_eq!("lazy-static-include", CONFIG.name);
+    assert_eq!(vec![1, 2, 3], CONFIG.values);
+    assert_eq!(2, VALUE["values"][1].as_i64().unwrap());
+    assert_eq!(*CONFIG, *CONFIG2);
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+#[should_panic(expected = "config-wrong.yaml")]
+fn include_yaml_wrong() {
+    lazy_static_include_yaml! {
+        runtime;
+        CONFIG: Config => "data/config-wrong.yaml",
+    }
+
+    let _ = &*CONFIG;
+}
+
+#[cfg(feature = "csv")]
+#[derive(Debug, PartialEq, CsvRecord)]
+struct Row {
+    name:  String,
+    id:    u32,
+    ratio: f64,
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn include_csv() {
+    lazy_static_include_csv! {
+        TEST: [Row] => "data/table.csv",
+    }
+
+    lazy_static_include_csv! {
+        embed;
+        TEST2: [Row] => "data/table.csv",
+    }
+
+    assert_eq!(2, TEST.len());
+    assert_eq!(
+        Row {
+            name: String::from("beta"), id: 2, ratio: 1.25
+        },
+        TEST[1]
+    );
+    assert_eq!(*TEST, *TEST2);
+}
+
+#[cfg(feature = "csv")]
+mod reexport {
+    pub use lazy_static_include as renamed;
+}
+
+#[cfg(feature = "csv")]
+#[derive(Debug, PartialEq, CsvRecord)]
+#[csv_record(crate = reexport::renamed)]
+struct RenamedRow {
+    id:   u32,
+    name: String,
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn include_csv_crate_path() {
+    lazy_static_include_csv! {
+        TEST: [RenamedRow] => "data/table.csv",
+    }
+
+    assert_eq!(
+        RenamedRow {
+            id: 1, name: String::from("alpha")
+        },
+        TEST[0]
+    );
+}
+
+#[cfg(feature = "csv")]
+#[test]
+#[should_panic(expected = "incorrect field \"x\" (line 3, column `id`)")]
+fn include_csv_wrong_field() {
+    lazy_static_include_csv! {
+        runtime;
+        TEST: [Row] => "data/table-wrong.csv",
+    }
+
+    let _ = TEST[0];
+}
+
+#[test]
+fn include_array_escape() {
+    lazy_static_include_array! {
+        runtime;
+        TEST: [&'static str; 4] => "data/string_array-escape.txt",
+        TEST2: [&'static [u8]; 3] => "data/bytes_array-escape.txt",
+    }
+
+    lazy_static_include_array! {
+        embed;
+        TEST3: [&'static str; 4] => "data/string_array-escape.txt",
+        TEST4: [&'static [u8]; 3] => "data/bytes_array-escape.txt",
+    }
+
+    assert_eq!(*TEST3, *TEST);
+    assert_eq!(*TEST4, *TEST2);
+    assert_eq!("line continued", TEST[3]);
+}
+
+#[test]
+fn include_array_expr() {
+    lazy_static_include_array! {
+        runtime;
+        TEST: [u32; 13] => "data/u32_array-expr.txt",
+        TEST2: [f64; 7] => "data/f64_array-expr.txt",
+        TEST3: [i16; 9] => "data/i16_array-expr.txt",
+    }
+
+    lazy_static_include_array! {
+        embed;
+        TEST4: [u32; 13] => "data/u32_array-expr.txt",
+        TEST5: [f64; 7] => "data/f64_array-expr.txt",
+        TEST6: [i16; 9] => "data/i16_array-expr.txt",
+    }
+
+    assert_eq!(*TEST4, *TEST);
+    assert_eq!(*TEST5, *TEST2);
+    assert_eq!(*TEST6, *TEST3);
+    assert_eq!([16, 17, u32::MAX, 65536, 3, 15, 255, u32::MAX, 2, 102, 1, 1, 8], *TEST);
+}
+
+#[test]
+#[allow(clippy::unnecessary_cast)]
+fn include_array_cast() {
+    lazy_static_include_array! {
+        runtime;
+        TEST: [u32; 5] => "data/u32_array-cast.txt",
+    }
+
+    lazy_static_include_array! {
+        embed;
+        TEST2: [u32; 5] => "data/u32_array-cast.txt",
+    }
+
+    assert_eq!(*TEST2, *TEST);
+    assert_eq!([u32::MAX, 4_000_000_000, u32::MAX, 2, 3], *TEST);
+}
+
+#[test]
+fn include_array_repeat() {
+    lazy_static_include_array! {
+        runtime;
+        TEST: [u8; 16] => "data/u8_array-repeat.txt",
+        TEST2: [[f32; 3]; 3] => "data/f32_matrix-repeat.txt",
+    }
+
+    lazy_static_include_array! {
+        embed;
+        TEST3: [u8; 16] => "data/u8_array-repeat.txt",
+        TEST4: [[f32; 3]; 3] => "data/f32_matrix-repeat.txt",
+    }
+
+    assert_eq!([0; 16], *TEST);
+    assert_eq!(*TEST3, *TEST);
+    assert_eq!([[0.0; 3], [1.5; 3], [-1.0, 2.0, 3.0]], *TEST2);
+    assert_eq!(*TEST4, *TEST2);
+}
+
+#[test]
+fn include_array_try_repeat_huge() {
+    lazy_static_include_array! {
+        runtime;
+        try TEST: [u8] => "data/u8_array-repeat-huge.txt",
+        try TEST2: [u8; 4] => "data/u8_array-repeat-huge.txt",
+    }
+
+    assert_eq!(
+        Err(ArrayIncludeError::TooLong {
+            length: 100_000_000_000_000, line: 1, column: 1
+        }),
+        *TEST
+    );
+    assert_eq!(
+        Err(ArrayIncludeError::WrongLength {
+            expected: 4, found: 100_000_000_000_000
+        }),
+        *TEST2
+    );
+}
+
+#[test]
+fn include_array_float_sentinel() {
+    lazy_static_include_array! {
+        runtime;
+        TEST: [f32; 8] => "data/f32_array-sentinel.txt",
+        TEST2: [[f64; 2]] => "data/f64_matrix-int.txt",
+    }
+
+    lazy_static_include_array! {
+        embed;
+        TEST3: [f32; 8] => "data/f32_array-sentinel.txt",
+        TEST4: [[f64; 2]] => "data/f64_matrix-int.txt",
+    }
+
+    for array in [&*TEST, &*TEST3] {
+        assert_eq!([5.0, -2.0, 16.0, 7.5], array[..4]);
+        assert!(array[4].is_nan());
+        assert_eq!([f32::INFINITY, f32::NEG_INFINITY, 1.0], array[5..]);
+    }
+
+    assert_eq!(&[[1.0, 2.5], [-3.0, 4.0]], *TEST2);
+    assert_eq!(*TEST4, *TEST2);
+}
+
+#[test]
+fn include_array_option() {
+    lazy_static_include_array! {
+        runtime;
+        TEST: [Option<u32>; 5] => "data/option_array.txt",
+        TEST2: [Option<f32>] => "data/option_f32_array.txt",
+    }
+
+    lazy_static_include_array! {
+        embed;
+        TEST3: [Option<u32>; 5] => "data/option_array.txt",
+        TEST4: [Option<f32>] => "data/option_f32_array.txt",
+    }
+
+    assert_eq!([Some(1), None, Some(16), None, Some(u32::MAX)], *TEST);
+    assert_eq!(*TEST3, *TEST);
+    assert_eq!(&[Some(5.0), None, Some(-2.5)], *TEST2);
+    assert_eq!(*TEST4, *TEST2);
+}
+
+#[test]
+fn include_array_non_zero() {
+    use std::num::{NonZero, NonZeroU16};
+
+    lazy_static_include_array! {
+        runtime;
+        TEST: [NonZeroU16; 3] => "data/non_zero_array.txt",
+        TEST2: [Option<NonZero<u8>>] => "data/option_non_zero_array.txt",
+    }
+
+    lazy_static_include_array! {
+        embed;
+        TEST3: [NonZeroU16; 3] => "data/non_zero_array.txt",
+        TEST4: [Option<NonZero<u8>>] => "data/option_non_zero_array.txt",
+    }
+
+    assert_eq!([1, 2, u16::MAX], TEST.map(NonZeroU16::get));
+    assert_eq!(*TEST3, *TEST);
+    assert_eq!(&[NonZero::new(7), None], *TEST2);
+    assert_eq!(*TEST4, *TEST2);
+}
+
+#[test]
+#[allow(unused_braces)]
+fn include_array_non_zero_block() {
+    lazy_static_include_array! {
+        embed;
+        TEST: [std::num::NonZeroU8; 2] => "data/non_zero_array-block.txt",
+    }
+
+    assert_eq!([5, 5], TEST.map(std::num::NonZeroU8::get));
+}
+
+#[test]
+fn include_array_try_non_zero_zero() {
+    lazy_static_include_array! {
+        runtime;
+        try TEST: [std::num::NonZeroU16; 3] => "data/non_zero_array-zero.txt",
+    }
+
+    assert_eq!(
+        Err(ArrayIncludeError::Overflow {
+            index:    2,
+            value:    String::from("0"),
+            expected: "NonZero<u16>",
+            line:     3,
+            column:   5,
+        }),
+        *TEST
+    );
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl IncludeElement for Color {
+    fn from_expr(expr: &Expr, index: usize) -> Result<Self, ArrayIncludeError> {
+        match expr.path() {
+            Some(["Color", "Red"]) => Ok(Color::Red),
+            Some(["Color", "Green"]) => Ok(Color::Green),
+            Some(["Color", "Blue"]) => Ok(Color::Blue),
+            _ => Err(Self::wrong_element_type(expr, index)),
+        }
+    }
+}
+
+#[test]
+fn include_array_custom_element() {
+    lazy_static_include_array! {
+        runtime;
+        TEST: [Color; 4] => "data/color_array.txt",
+    }
+
+    lazy_static_include_array! {
+        embed;
+        TEST2: [Color; 4] => "data/color_array.txt",
+    }
+
+    assert_eq!([Color::Red, Color::Green, Color::Blue, Color::Green], *TEST);
+    assert_eq!(*TEST2, *TEST);
+}
+
+#[test]
+fn include_array_try_custom_element_wrong() {
+    lazy_static_include_array! {
+        runtime;
+        try TEST: [Color] => "data/color_array-wrong.txt",
+    }
+
+    assert_eq!(
+        Err(ArrayIncludeError::WrongElementType {
+            index:       1,
+            expected:    "macros::Color",
+            found_token: String::from("Color::Purple"),
+            line:        3,
+            column:      5,
+        }),
+        *TEST
+    );
+}
+
+#[test]
+fn include_array_try_expr_overflow() {
+    lazy_static_include_array! {
+        runtime;
+        try TEST: [u8; 2] => "data/u8_array-expr-overflow.txt",
+    }
+
+    assert_eq!(
+        Err(ArrayIncludeError::Overflow {
+            index:    1,
+            value:    String::from("200 + 56"),
+            expected: "u8",
+            line:     3,
+            column:   5,
+        }),
+        *TEST
+    );
+}
+
+#[test]
+fn include_array_try_cast_overflow() {
+    lazy_static_include_array! {
+        runtime;
+        try TEST: [u8; 2] => "data/u8_array-cast-overflow.txt",
+    }
+
+    assert_eq!(
+        Err(ArrayIncludeError::Overflow {
+            index:    1,
+            value:    String::from("300"),
+            expected: "u8",
+            line:     3,
+            column:   5,
+        }),
+        *TEST
+    );
+}
+
+#[test]
+fn include_map() {
+    lazy_static_include_map! {
+        runtime;
+        MIME: Map<&'static str, &'static str> => "data/mime.txt",
+        STATUS: Map<i16, &'static str> => "data/status_map.txt",
+    }
+
+    lazy_static_include_map! {
+        embed;
+        MIME2: Map<&'static str, &'static str> => "data/mime.txt",
+        STATUS2: Map<i16, &'static str> => "data/status_map.txt",
+    }
+
+    for (mime, status) in [(&*MIME, &*STATUS), (&*MIME2, &*STATUS2)] {
+        assert_eq!(4, mime.len());
+        assert_eq!(Some(&"text/html"), mime.get("html"));
+        assert_eq!("text/css", mime["css"]);
+        assert!(!mime.contains_key("txt"));
+        assert_eq!(vec![&"css", &"html", &"json", &"png"], mime.keys().collect::<Vec<_>>());
+
+        assert_eq!(Some(&"Not Found"), status.get(&404));
+        assert_eq!(vec![&-1, &200, &404, &500], status.keys().collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn include_map_comments() {
+    lazy_static_include_map! {
+        runtime;
+        MIME: Map<&'static str, &'static str> => "data/mime-comments.txt",
+    }
+
+    lazy_static_include_map! {
+        embed;
+        MIME2: Map<&'static str, &'static str> => "data/mime-comments.txt",
+    }
+
+    for mime in [&*MIME, &*MIME2] {
+        assert_eq!(
+            vec![(&"css", &"text/css"), (&"html", &"text/html"), (&"png", &"image/png")],
+            mime.iter().collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn include_map_array_block_comment() {
+    lazy_static_include_map! {
+        runtime;
+        MAP: Map<&'static str, u8> => "data/map-block-comment.txt",
+    }
+
+    lazy_static_include_map! {
+        embed;
+        MAP2: Map<&'static str, u8> => "data/map-block-comment.txt",
+    }
+
+    for map in [&*MAP, &*MAP2] {
+        assert_eq!(vec![(&"a", &2), (&"b", &1)], map.iter().collect::<Vec<_>>());
+    }
+}
+
+#[test]
+#[should_panic(expected = "duplicate key \"json\" (line 3, column 1)")]
+fn include_map_duplicate_key() {
+    lazy_static_include_map! {
+        runtime;
+        MIME: Map<&'static str, &'static str> => "data/mime-duplicate.txt",
+    }
+
+    let _ = MIME.len();
+}
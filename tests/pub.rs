@@ -4,16 +4,78 @@ mod test_mod;
 fn include_str() {
     assert_eq!("This is just a test text.", *test_mod::STR);
     assert_eq!("This is just a test text.", *test_mod::STR2);
+    assert_eq!("This is just a test text.", test_mod::HOT_STR.get());
+    assert_eq!("This is just a test text.", test_mod::HOT_STR2.get());
 }
 
 #[test]
 fn include_bytes() {
     assert_eq!("This is just a test text.".as_bytes(), *test_mod::BYTE);
     assert_eq!("This is just a test text.".as_bytes(), *test_mod::BYTE2);
+    assert_eq!("This is just a test text.".as_bytes(), test_mod::HOT_BYTE.get());
+    assert_eq!("This is just a test text.".as_bytes(), test_mod::HOT_BYTE2.get());
+}
+
+#[cfg(feature = "verify")]
+#[test]
+fn include_verify() {
+    assert_eq!("This is just a test text.", *test_mod::VERIFY_STR);
+    assert_eq!(test_mod::VERIFY_STR_HASH, test_mod::VERIFY_STR2_HASH);
+    assert_eq!("This is just a test text.".as_bytes(), *test_mod::VERIFY_BYTE);
+    assert_eq!(test_mod::VERIFY_BYTE_HASH, test_mod::VERIFY_BYTE2_HASH);
 }
 
 #[test]
 fn include_array() {
     assert_eq!(123, test_mod::ARRAY[0]);
     assert_eq!(123, test_mod::ARRAY2[0]);
+    assert_eq!(123, test_mod::SLICE[0]);
+    assert_eq!(123, test_mod::SLICE2[0]);
+}
+
+#[test]
+fn include_map() {
+    assert_eq!("image/png", test_mod::MAP["png"]);
+    assert_eq!("OK", test_mod::MAP2[&200]);
+}
+
+#[test]
+fn include_dir() {
+    assert_eq!("Hello", test_mod::DIR["hello.txt"]);
+    assert_eq!("Hello".as_bytes(), test_mod::DIR2["hello.txt"]);
+}
+
+#[cfg(feature = "glob")]
+#[test]
+fn include_glob() {
+    assert_eq!([("data/dir/hello.txt", "Hello")], *test_mod::GLOB);
+    assert_eq!([("data/dir/hello.txt", "Hello".as_bytes())], *test_mod::GLOB2);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn include_json() {
+    assert_eq!("lazy-static-include", test_mod::JSON["name"]);
+    assert_eq!("lazy-static-include", test_mod::JSON2["name"]);
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn include_toml() {
+    assert_eq!("lazy-static-include", test_mod::TOML["name"].as_str().unwrap());
+    assert_eq!("lazy-static-include", test_mod::TOML2["name"].as_str().unwrap());
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn include_yaml() {
+    assert_eq!("lazy-static-include", test_mod::YAML["name"].as_str().unwrap());
+    assert_eq!("lazy-static-include", test_mod::YAML2["name"].as_str().unwrap());
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn include_csv() {
+    assert_eq!(1, test_mod::CSV[0].id);
+    assert_eq!(1, test_mod::CSV2[0].id);
 }
@@ -0,0 +1,54 @@
+use std::fs;
+
+use lazy_static_include::*;
+
+lazy_static_include_str! {
+    runtime;
+    TEST => "data/test.txt",
+}
+
+lazy_static_include_str! {
+    runtime;
+    hot HOT => "data/test.txt",
+}
+
+#[cfg(feature = "verify")]
+lazy_static_include_str! {
+    runtime;
+    verify VERIFY => "data/test.txt",
+}
+
+lazy_static_include_dir! {
+    runtime;
+    DIR: str => "data/dir",
+}
+
+#[test]
+fn rebase_onto_root() {
+    let root = std::env::temp_dir().join("lazy-static-include-root");
+
+    fs::create_dir_all(root.join("data/dir")).unwrap();
+    fs::write(root.join("data/test.txt"), "Relocated").unwrap();
+    fs::write(root.join("data/dir/relocated.txt"), "Hello").unwrap();
+
+    set_root(&root).unwrap();
+
+    assert_eq!(Some(root.as_path()), lazy_static_include::root());
+    assert!(set_root("data").is_err());
+
+    assert_eq!("Relocated", *TEST);
+    assert_eq!("Relocated", HOT.get());
+    assert_eq!(Some(&"Hello"), DIR.get("relocated.txt"));
+
+    // The relocated file is not the one hashed at compile time.
+    #[cfg(feature = "verify")]
+    {
+        let err = std::panic::catch_unwind(|| *VERIFY).unwrap_err();
+
+        assert!(
+            err.downcast_ref::<String>().unwrap().contains("has been modified since compilation")
+        );
+    }
+
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -5,12 +5,85 @@ lazy_static_include_str! {
     pub(crate) STR2 => "data/test.txt",
 }
 
+lazy_static_include_str! {
+    pub hot HOT_STR => "data/test.txt",
+    pub(crate) hot HOT_STR2 => "data/test.txt",
+}
+
+#[cfg(feature = "verify")]
+lazy_static_include_str! {
+    pub verify VERIFY_STR => "data/test.txt",
+    pub(crate) verify VERIFY_STR2 => "data/test.txt",
+}
+
 lazy_static_include_bytes! {
     pub BYTE => "data/test.txt",
     pub(crate) BYTE2 => "data/test.txt",
 }
 
+lazy_static_include_bytes! {
+    pub hot HOT_BYTE => "data/test.txt",
+    pub(crate) hot HOT_BYTE2 => "data/test.txt",
+}
+
+#[cfg(feature = "verify")]
+lazy_static_include_bytes! {
+    pub verify VERIFY_BYTE => "data/test.txt",
+    pub(crate) verify VERIFY_BYTE2 => "data/test.txt",
+}
+
 lazy_static_include_array! {
     pub ARRAY: [isize; 5] => "data/isize_array.txt",
     pub(crate) ARRAY2: [isize; 5] => "data/isize_array.txt",
 }
+
+lazy_static_include_array! {
+    pub SLICE: [isize] => "data/isize_array.txt",
+    pub(crate) SLICE2: [isize] => "data/isize_array.txt",
+}
+
+lazy_static_include_map! {
+    pub MAP: Map<&'static str, &'static str> => "data/mime.txt",
+    pub(crate) MAP2: Map<i16, &'static str> => "data/status_map.txt",
+}
+
+lazy_static_include_dir! {
+    pub DIR: str => "data/dir",
+    pub(crate) DIR2: [u8] => "data/dir",
+}
+
+#[cfg(feature = "glob")]
+lazy_static_include_glob! {
+    pub GLOB: str => "data/dir/*.txt",
+    pub(crate) GLOB2: [u8] => "data/dir/*.txt",
+}
+
+#[cfg(feature = "json")]
+lazy_static_include_json! {
+    pub JSON: serde_json::Value => "data/config.json",
+    pub(crate) JSON2: serde_json::Value => "data/config.json",
+}
+
+#[cfg(feature = "toml")]
+lazy_static_include_toml! {
+    pub TOML: toml::Table => "data/config.toml",
+    pub(crate) TOML2: toml::Table => "data/config.toml",
+}
+
+#[cfg(feature = "yaml")]
+lazy_static_include_yaml! {
+    pub YAML: serde_yaml_ng::Value => "data/config.yaml",
+    pub(crate) YAML2: serde_yaml_ng::Value => "data/config.yaml",
+}
+
+#[cfg(feature = "csv")]
+#[derive(CsvRecord)]
+pub struct Row {
+    pub id: u32,
+}
+
+#[cfg(feature = "csv")]
+lazy_static_include_csv! {
+    pub CSV: [Row] => "data/table.csv",
+    pub(crate) CSV2: [Row] => "data/table.csv",
+}